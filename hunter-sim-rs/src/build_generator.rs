@@ -1,6 +1,42 @@
-use rand::Rng;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::cell::RefCell;
 use std::collections::{HashMap, HashSet};
 use serde::{Deserialize, Serialize};
+use crate::config::BuildConfig;
+use crate::simulation::run_and_aggregate;
+use crate::stats::AggregatedStats;
+
+/// A candidate build as produced by any of `BuildGenerator`'s search strategies: talent points,
+/// attribute points, and an equipment loadout (slot name -> chosen item name).
+pub type Build = (HashMap<String, i32>, HashMap<String, i32>, HashMap<String, String>);
+
+/// Controls how a build's simulated stats are collapsed into a single fitness score.
+#[derive(Debug, Clone, Copy)]
+pub struct FitnessWeights {
+    pub avg_stage: f64,
+    pub survival_rate: f64,
+    pub avg_loot_per_hour: f64,
+}
+
+impl Default for FitnessWeights {
+    /// Matches the tool's original behavior: rank purely by average stage reached.
+    fn default() -> Self {
+        Self {
+            avg_stage: 1.0,
+            survival_rate: 0.0,
+            avg_loot_per_hour: 0.0,
+        }
+    }
+}
+
+impl FitnessWeights {
+    fn score(&self, stats: &AggregatedStats) -> f64 {
+        self.avg_stage * stats.avg_stage
+            + self.survival_rate * stats.survival_rate * 100.0
+            + self.avg_loot_per_hour * stats.avg_loot_per_hour
+    }
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AttributeInfo {
@@ -14,6 +50,18 @@ pub struct TalentInfo {
     pub max: i32,
 }
 
+/// An equippable item in a loadout slot (e.g. "weapon", "armor", "relic_slot_1"). Equipping an
+/// item additively overlays `stat_modifiers` onto the build's stats at evaluation time.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EquipmentItem {
+    pub name: String,
+    #[serde(default)]
+    pub stat_modifiers: HashMap<String, i32>,
+    /// Other item names that must already be equipped (in any slot) for this item to be legal.
+    #[serde(default)]
+    pub prerequisites: Vec<String>,
+}
+
 #[derive(Debug, Clone)]
 pub struct BuildGenerator {
     pub talent_points: i32,
@@ -24,6 +72,34 @@ pub struct BuildGenerator {
     pub attribute_point_gates: HashMap<String, i32>,
     pub attribute_exclusions: Vec<(String, String)>,
     pub dynamic_attr_maxes: HashMap<String, i32>,
+    /// Items available per equipment slot (e.g. "weapon" -> [sword, axe, ...]).
+    pub equipment_slots: HashMap<String, Vec<EquipmentItem>>,
+    /// Pairs of item names that can't both be equipped at once, regardless of slot.
+    pub slot_exclusions: Vec<(String, String)>,
+    /// Seed backing this generator's RNG. Identical seeds replay identical RNG draws, but the
+    /// talent/attribute walks follow `HashMap` key order, which differs between map instances and
+    /// processes, so identical seeds do not by themselves reproduce identical builds.
+    pub seed: u64,
+    rng: RefCell<StdRng>,
+}
+
+/// One legal point spend considered by MCTS: a talent point, an attribute point, or equipping
+/// an item into a slot.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum PointChoice {
+    Talent(String),
+    Attribute(String),
+    Equipment(String, String),
+}
+
+/// A node in the MCTS search tree: a partial build plus the usual visit/value bookkeeping.
+struct MctsNode {
+    build: Build,
+    parent: Option<usize>,
+    children: Vec<(PointChoice, usize)>,
+    untried: Vec<PointChoice>,
+    visits: u32,
+    total_value: f64,
 }
 
 impl BuildGenerator {
@@ -35,6 +111,7 @@ impl BuildGenerator {
         attribute_point_gates: HashMap<String, i32>,
         attribute_exclusions: Vec<(String, String)>,
     ) -> Self {
+        let seed = rand::thread_rng().gen();
         let mut gen = Self {
             talent_points: level,
             attribute_points: level * 3,
@@ -44,72 +121,100 @@ impl BuildGenerator {
             attribute_point_gates,
             attribute_exclusions,
             dynamic_attr_maxes: HashMap::new(),
+            equipment_slots: HashMap::new(),
+            slot_exclusions: Vec::new(),
+            seed,
+            rng: RefCell::new(StdRng::seed_from_u64(seed)),
         };
-        
+
         gen.calculate_dynamic_attr_maxes();
         gen
     }
-    
+
+    /// Fix this generator's RNG to a specific seed. This fixes the RNG's draws only: the random
+    /// walks in `generate_builds`/`evolve`/`search_mcts` index into `HashMap` key order, which
+    /// differs between map instances and processes, and their fitness evaluations go through
+    /// `run_and_aggregate`, which is seeded only when `config.seed` is set. Output is therefore
+    /// not guaranteed to be identical across runs.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self.rng = RefCell::new(StdRng::seed_from_u64(seed));
+        self
+    }
+
+    /// Attach an equipment loadout search space: one item pool per slot, plus cross-slot
+    /// exclusions (e.g. two items that share a set bonus the game forbids stacking).
+    pub fn with_equipment(
+        mut self,
+        equipment_slots: HashMap<String, Vec<EquipmentItem>>,
+        slot_exclusions: Vec<(String, String)>,
+    ) -> Self {
+        self.equipment_slots = equipment_slots;
+        self.slot_exclusions = slot_exclusions;
+        self
+    }
+
     fn calculate_dynamic_attr_maxes(&mut self) {
         // Find unlimited attributes
         let unlimited_attrs: Vec<String> = self.attributes.iter()
             .filter(|(_, info)| info.max.is_infinite())
             .map(|(name, _)| name.clone())
             .collect();
-        
+
         // Calculate cost to max all limited attributes
         let limited_attr_cost: i32 = self.attributes.iter()
             .filter(|(_, info)| !info.max.is_infinite())
             .map(|(_, info)| info.cost * info.max as i32)
             .sum();
-        
+
         // Share remaining budget among unlimited attributes
         if !unlimited_attrs.is_empty() {
             let remaining_budget = self.attribute_points - limited_attr_cost;
             let max_per_unlimited = (remaining_budget / unlimited_attrs.len() as i32).max(1);
-            
+
             for attr in unlimited_attrs {
                 self.dynamic_attr_maxes.insert(attr, max_per_unlimited);
             }
         }
     }
-    
+
     fn get_attr_max(&self, attr: &str) -> i32 {
         if let Some(&dynamic_max) = self.dynamic_attr_maxes.get(attr) {
             return dynamic_max;
         }
-        
+
         if let Some(info) = self.attributes.get(attr) {
             if info.max.is_infinite() {
                 return 250; // Fallback
             }
             return info.max as i32;
         }
-        
+
         0
     }
-    
-    pub fn generate_random_build(&self) -> (HashMap<String, i32>, HashMap<String, i32>) {
+
+    pub fn generate_random_build(&self) -> Build {
         let talents = self.random_walk_talent_allocation();
         let attrs = self.random_walk_attr_allocation();
-        (talents, attrs)
+        let equipment = self.random_walk_equipment_allocation();
+        (talents, attrs, equipment)
     }
-    
-    pub fn generate_builds(&self, count: usize) -> Vec<(HashMap<String, i32>, HashMap<String, i32>)> {
+
+    pub fn generate_builds(&self, count: usize) -> Vec<Build> {
         (0..count)
             .map(|_| self.generate_random_build())
             .collect()
     }
-    
+
     fn random_walk_talent_allocation(&self) -> HashMap<String, i32> {
-        let mut rng = rand::thread_rng();
+        let mut rng = self.rng.borrow_mut();
         let mut result: HashMap<String, i32> = self.talents.keys()
             .map(|k| (k.clone(), 0))
             .collect();
-        
+
         let mut remaining = self.talent_points;
         let talent_names: Vec<String> = self.talents.keys().cloned().collect();
-        
+
         while remaining > 0 {
             // Find valid talents that can accept +1 point
             let valid_talents: Vec<&String> = talent_names.iter()
@@ -121,20 +226,20 @@ impl BuildGenerator {
                     }
                 })
                 .collect();
-            
+
             if valid_talents.is_empty() {
                 break;
             }
-            
+
             // Pick random and add 1 point
             let chosen = valid_talents[rng.gen_range(0..valid_talents.len())];
             *result.get_mut(chosen).unwrap() += 1;
             remaining -= 1;
         }
-        
+
         result
     }
-    
+
     fn can_unlock_attribute(&self, attr: &str, current: &HashMap<String, i32>) -> bool {
         // Check point gate
         if let Some(&required_points) = self.attribute_point_gates.get(attr) {
@@ -149,67 +254,67 @@ impl BuildGenerator {
                     }
                 })
                 .sum();
-            
+
             if points_spent < required_points {
                 return false;
             }
         }
-        
+
         true
     }
-    
+
     fn random_walk_attr_allocation(&self) -> HashMap<String, i32> {
-        let mut rng = rand::thread_rng();
+        let mut rng = self.rng.borrow_mut();
         let mut result: HashMap<String, i32> = self.attributes.keys()
             .map(|k| (k.clone(), 0))
             .collect();
-        
+
         let mut remaining = self.attribute_points;
         let attr_names: Vec<String> = self.attributes.keys().cloned().collect();
-        
+
         let max_iterations = 10000;
         let mut iteration = 0;
         let mut stuck_count = 0;
-        
+
         while remaining > 0 && iteration < max_iterations {
             iteration += 1;
-            
+
             // Find valid attributes
             let mut valid_attrs = Vec::new();
-            
+
             for attr in &attr_names {
                 let info = match self.attributes.get(attr) {
                     Some(i) => i,
                     None => continue,
                 };
-                
+
                 // Check cost
                 if info.cost > remaining {
                     continue;
                 }
-                
+
                 // Check max level
                 let max_lvl = self.get_attr_max(attr);
                 if result[attr] >= max_lvl {
                     continue;
                 }
-                
+
                 // Check dependencies
                 if let Some(deps) = self.attribute_dependencies.get(attr) {
                     let can_use = deps.iter().all(|(req_attr, &req_level)| {
                         result.get(req_attr).copied().unwrap_or(0) >= req_level
                     });
-                    
+
                     if !can_use {
                         continue;
                     }
                 }
-                
+
                 // Check point gates
                 if !self.can_unlock_attribute(attr, &result) {
                     continue;
                 }
-                
+
                 // Check exclusions
                 let mut excluded = false;
                 for (a, b) in &self.attribute_exclusions {
@@ -222,14 +327,14 @@ impl BuildGenerator {
                         break;
                     }
                 }
-                
+
                 if excluded {
                     continue;
                 }
-                
+
                 valid_attrs.push(attr.clone());
             }
-            
+
             if valid_attrs.is_empty() {
                 stuck_count += 1;
                 if stuck_count >= 3 {
@@ -237,7 +342,7 @@ impl BuildGenerator {
                 }
             } else {
                 stuck_count = 0;
-                
+
                 // Pick random and add 1 point
                 let chosen = &valid_attrs[rng.gen_range(0..valid_attrs.len())];
                 let cost = self.attributes[chosen].cost;
@@ -245,7 +350,7 @@ impl BuildGenerator {
                 remaining -= cost;
             }
         }
-        
+
         // Validate total cost
         let total_spent: i32 = result.iter()
             .map(|(k, &v)| {
@@ -256,14 +361,593 @@ impl BuildGenerator {
                 }
             })
             .sum();
-        
+
         if total_spent > self.attribute_points {
             // Invalid - return empty
             return self.attributes.keys()
                 .map(|k| (k.clone(), 0))
                 .collect();
         }
-        
+
         result
     }
+
+    /// Whether `item` can legally join `equipped` (prerequisites satisfied, no exclusion hit).
+    fn can_equip_item(&self, item: &EquipmentItem, equipped: &HashMap<String, String>) -> bool {
+        let equipped_names: HashSet<&str> = equipped.values().map(|s| s.as_str()).collect();
+
+        if !item.prerequisites.iter().all(|p| equipped_names.contains(p.as_str())) {
+            return false;
+        }
+
+        for (a, b) in &self.slot_exclusions {
+            if item.name == *a && equipped_names.contains(b.as_str()) {
+                return false;
+            }
+            if item.name == *b && equipped_names.contains(a.as_str()) {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Randomly fill each equipment slot with a legal item, leaving a slot empty if none of its
+    /// items currently satisfy prerequisites/exclusions. Slots are visited in a stable (sorted)
+    /// order so prerequisite items tend to land before items that depend on them.
+    fn random_walk_equipment_allocation(&self) -> HashMap<String, String> {
+        let mut rng = self.rng.borrow_mut();
+        let mut result: HashMap<String, String> = HashMap::new();
+
+        let mut slot_names: Vec<&String> = self.equipment_slots.keys().collect();
+        slot_names.sort();
+
+        for slot in slot_names {
+            let items = &self.equipment_slots[slot];
+            let valid: Vec<&EquipmentItem> = items.iter()
+                .filter(|item| self.can_equip_item(item, &result))
+                .collect();
+
+            if valid.is_empty() {
+                continue;
+            }
+
+            let chosen = valid[rng.gen_range(0..valid.len())];
+            result.insert(slot.clone(), chosen.name.clone());
+        }
+
+        result
+    }
+
+    fn find_equipment_item(&self, name: &str) -> Option<&EquipmentItem> {
+        self.equipment_slots.values()
+            .flat_map(|items| items.iter())
+            .find(|item| item.name == name)
+    }
+
+    /// Build a `BuildConfig` for simulation by overlaying a genome's talents/attrs onto `base`,
+    /// then additively applying each equipped item's stat modifiers.
+    fn apply_build(&self, base: &BuildConfig, build: &Build) -> BuildConfig {
+        let mut config = base.clone();
+        config.talents = build.0.clone();
+        config.attributes = build.1.clone();
+        for item_name in build.2.values() {
+            if let Some(item) = self.find_equipment_item(item_name) {
+                for (stat, modifier) in &item.stat_modifiers {
+                    *config.stats.entry(stat.clone()).or_insert(0) += modifier;
+                }
+            }
+        }
+        config
+    }
+
+    /// Score a build by running `num_sims` simulations and collapsing the result via `weights`.
+    fn evaluate(&self, base: &BuildConfig, build: &Build, num_sims: usize, weights: FitnessWeights) -> (f64, AggregatedStats) {
+        let config = self.apply_build(base, build);
+        let stats = run_and_aggregate(&config, num_sims, false);
+        (weights.score(&stats), stats)
+    }
+
+    /// Re-walk validity checks (budgets, maxes, dependencies, gates, exclusions) over a build
+    /// produced by crossover/mutation, dropping illegal points and re-spending any leftover
+    /// budget legally via the existing random-walk machinery.
+    pub fn repair(&self, build: &Build) -> Build {
+        let (talents, attrs, equipment) = build;
+
+        // Repair talents: drop points beyond each talent's max, then fill any freed budget.
+        let mut repaired_talents: HashMap<String, i32> = self.talents.keys()
+            .map(|k| (k.clone(), 0))
+            .collect();
+        let mut talent_remaining = self.talent_points;
+        for (name, &pts) in talents {
+            if let Some(info) = self.talents.get(name) {
+                let capped = pts.clamp(0, info.max).min(talent_remaining);
+                repaired_talents.insert(name.clone(), capped);
+                talent_remaining -= capped;
+            }
+        }
+        self.fill_remaining_talents(&mut repaired_talents, talent_remaining);
+
+        // Repair attributes: re-apply each point one at a time through the same legality
+        // checks as `random_walk_attr_allocation`, dropping anything that violates
+        // dependencies/gates/exclusions, then spend whatever budget is left over legally.
+        let mut repaired_attrs: HashMap<String, i32> = self.attributes.keys()
+            .map(|k| (k.clone(), 0))
+            .collect();
+        let mut attr_remaining = self.attribute_points;
+
+        let mut attr_names: Vec<String> = attrs.keys().cloned().collect();
+        attr_names.sort();
+        for name in &attr_names {
+            let requested = *attrs.get(name).unwrap_or(&0);
+            for _ in 0..requested {
+                if !self.can_add_attr_point(name, &repaired_attrs, attr_remaining) {
+                    break;
+                }
+                let cost = self.attributes[name].cost;
+                *repaired_attrs.get_mut(name).unwrap() += 1;
+                attr_remaining -= cost;
+            }
+        }
+        self.fill_remaining_attrs(&mut repaired_attrs, attr_remaining);
+
+        // Repair equipment: re-equip each slot one at a time in stable order, dropping items
+        // whose prerequisites/exclusions no longer hold, then fill any now-empty slots legally.
+        let mut repaired_equipment: HashMap<String, String> = HashMap::new();
+        let mut slot_names: Vec<String> = self.equipment_slots.keys().cloned().collect();
+        slot_names.sort();
+        for slot in &slot_names {
+            if let Some(item_name) = equipment.get(slot) {
+                if let Some(item) = self.equipment_slots[slot].iter().find(|i| &i.name == item_name) {
+                    if self.can_equip_item(item, &repaired_equipment) {
+                        repaired_equipment.insert(slot.clone(), item.name.clone());
+                    }
+                }
+            }
+        }
+        self.fill_remaining_equipment(&mut repaired_equipment);
+
+        (repaired_talents, repaired_attrs, repaired_equipment)
+    }
+
+    fn can_add_attr_point(&self, attr: &str, current: &HashMap<String, i32>, remaining: i32) -> bool {
+        let info = match self.attributes.get(attr) {
+            Some(i) => i,
+            None => return false,
+        };
+
+        if info.cost > remaining {
+            return false;
+        }
+
+        let max_lvl = self.get_attr_max(attr);
+        if current.get(attr).copied().unwrap_or(0) >= max_lvl {
+            return false;
+        }
+
+        if let Some(deps) = self.attribute_dependencies.get(attr) {
+            let can_use = deps.iter().all(|(req_attr, &req_level)| {
+                current.get(req_attr).copied().unwrap_or(0) >= req_level
+            });
+            if !can_use {
+                return false;
+            }
+        }
+
+        if !self.can_unlock_attribute(attr, current) {
+            return false;
+        }
+
+        for (a, b) in &self.attribute_exclusions {
+            if attr == a && current.get(b).copied().unwrap_or(0) > 0 {
+                return false;
+            }
+            if attr == b && current.get(a).copied().unwrap_or(0) > 0 {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Spend any leftover talent budget using the same random-walk strategy as a fresh build.
+    fn fill_remaining_talents(&self, result: &mut HashMap<String, i32>, mut remaining: i32) {
+        let mut rng = self.rng.borrow_mut();
+        let talent_names: Vec<String> = self.talents.keys().cloned().collect();
+
+        while remaining > 0 {
+            let valid: Vec<&String> = talent_names.iter()
+                .filter(|&t| {
+                    self.talents.get(t).map(|info| result[t] < info.max).unwrap_or(false)
+                })
+                .collect();
+
+            if valid.is_empty() {
+                break;
+            }
+
+            let chosen = valid[rng.gen_range(0..valid.len())];
+            *result.get_mut(chosen).unwrap() += 1;
+            remaining -= 1;
+        }
+    }
+
+    /// Spend any leftover attribute budget using the same random-walk strategy as a fresh build.
+    fn fill_remaining_attrs(&self, result: &mut HashMap<String, i32>, mut remaining: i32) {
+        let mut rng = self.rng.borrow_mut();
+        let attr_names: Vec<String> = self.attributes.keys().cloned().collect();
+        let mut stuck_count = 0;
+
+        while remaining > 0 {
+            let valid: Vec<&String> = attr_names.iter()
+                .filter(|&a| self.can_add_attr_point(a, result, remaining))
+                .collect();
+
+            if valid.is_empty() {
+                stuck_count += 1;
+                if stuck_count >= 3 {
+                    break;
+                }
+                continue;
+            }
+            stuck_count = 0;
+
+            let chosen = &valid[rng.gen_range(0..valid.len())];
+            let cost = self.attributes[chosen.as_str()].cost;
+            *result.get_mut(chosen.as_str()).unwrap() += 1;
+            remaining -= cost;
+        }
+    }
+
+    /// Fill any slot missing from `result` with a randomly chosen legal item, leaving it empty
+    /// if nothing in the pool currently qualifies.
+    fn fill_remaining_equipment(&self, result: &mut HashMap<String, String>) {
+        let mut rng = self.rng.borrow_mut();
+        let mut slot_names: Vec<&String> = self.equipment_slots.keys().collect();
+        slot_names.sort();
+
+        for slot in slot_names {
+            if result.contains_key(slot) {
+                continue;
+            }
+
+            let valid: Vec<&EquipmentItem> = self.equipment_slots[slot].iter()
+                .filter(|item| self.can_equip_item(item, result))
+                .collect();
+
+            if valid.is_empty() {
+                continue;
+            }
+
+            let chosen = valid[rng.gen_range(0..valid.len())];
+            result.insert(slot.clone(), chosen.name.clone());
+        }
+    }
+
+    /// Uniform crossover: each talent/attribute's point count, and each slot's item, is taken
+    /// from one parent or the other with equal probability.
+    fn crossover(&self, a: &Build, b: &Build) -> Build {
+        let mut rng = self.rng.borrow_mut();
+
+        let talents = self.talents.keys()
+            .map(|k| {
+                let from_a = a.0.get(k).copied().unwrap_or(0);
+                let from_b = b.0.get(k).copied().unwrap_or(0);
+                (k.clone(), if rng.gen_bool(0.5) { from_a } else { from_b })
+            })
+            .collect();
+
+        let attrs = self.attributes.keys()
+            .map(|k| {
+                let from_a = a.1.get(k).copied().unwrap_or(0);
+                let from_b = b.1.get(k).copied().unwrap_or(0);
+                (k.clone(), if rng.gen_bool(0.5) { from_a } else { from_b })
+            })
+            .collect();
+
+        let mut equipment = HashMap::new();
+        for slot in self.equipment_slots.keys() {
+            let from_a = a.2.get(slot);
+            let from_b = b.2.get(slot);
+            let chosen = if rng.gen_bool(0.5) { from_a.or(from_b) } else { from_b.or(from_a) };
+            if let Some(item_name) = chosen {
+                equipment.insert(slot.clone(), item_name.clone());
+            }
+        }
+
+        (talents, attrs, equipment)
+    }
+
+    /// Mutate a build by shifting a handful of points from one entry to another, and
+    /// occasionally re-rolling a single equipment slot.
+    fn mutate(&self, build: &Build, shifts: usize) -> Build {
+        let mut rng = self.rng.borrow_mut();
+        let (mut talents, mut attrs, mut equipment) = build.clone();
+
+        for _ in 0..shifts {
+            if !self.equipment_slots.is_empty() && rng.gen_bool(0.2) {
+                let mut slot_names: Vec<&String> = self.equipment_slots.keys().collect();
+                slot_names.sort();
+                let slot = slot_names[rng.gen_range(0..slot_names.len())];
+                let valid: Vec<&EquipmentItem> = self.equipment_slots[slot].iter()
+                    .filter(|item| self.can_equip_item(item, &equipment))
+                    .collect();
+                if valid.is_empty() {
+                    equipment.remove(slot);
+                } else {
+                    let chosen = valid[rng.gen_range(0..valid.len())];
+                    equipment.insert(slot.clone(), chosen.name.clone());
+                }
+            } else if rng.gen_bool(0.5) {
+                let names: Vec<&String> = talents.keys().collect();
+                if names.len() < 2 {
+                    continue;
+                }
+                let from = names[rng.gen_range(0..names.len())].clone();
+                let to = names[rng.gen_range(0..names.len())].clone();
+                if from != to && talents[&from] > 0 {
+                    *talents.get_mut(&from).unwrap() -= 1;
+                    *talents.get_mut(&to).unwrap() += 1;
+                }
+            } else {
+                let names: Vec<&String> = attrs.keys().collect();
+                if names.len() < 2 {
+                    continue;
+                }
+                let from = names[rng.gen_range(0..names.len())].clone();
+                let to = names[rng.gen_range(0..names.len())].clone();
+                if from != to && attrs[&from] > 0 {
+                    *attrs.get_mut(&from).unwrap() -= 1;
+                    *attrs.get_mut(&to).unwrap() += 1;
+                }
+            }
+        }
+
+        (talents, attrs, equipment)
+    }
+
+    /// Tournament selection: sample `tournament_size` candidates and return the fittest.
+    fn tournament_select<'a>(&self, population: &'a [(Build, f64)], tournament_size: usize) -> &'a Build {
+        let mut rng = self.rng.borrow_mut();
+        let mut best: Option<&(Build, f64)> = None;
+
+        for _ in 0..tournament_size.max(1) {
+            let candidate = &population[rng.gen_range(0..population.len())];
+            if best.map(|b| candidate.1 > b.1).unwrap_or(true) {
+                best = Some(candidate);
+            }
+        }
+
+        &best.unwrap().0
+    }
+
+    /// Evolve builds toward higher fitness via a genetic algorithm.
+    ///
+    /// Seeds the population with `generate_builds`, then for `generations` iterations selects
+    /// parents by tournament selection, recombines them with uniform crossover, mutates every
+    /// child, repairs any resulting illegal allocation, and re-scores via
+    /// `run_and_aggregate`. Returns the top `top_n` builds sorted by descending fitness along
+    /// with their aggregated stats.
+    pub fn evolve(
+        &self,
+        generations: usize,
+        population_size: usize,
+        config: &BuildConfig,
+        weights: FitnessWeights,
+        sims_per_eval: usize,
+        top_n: usize,
+    ) -> Vec<(Build, AggregatedStats)> {
+        let tournament_size = (population_size / 5).max(2);
+
+        let mut population: Vec<(Build, f64)> = self.generate_builds(population_size)
+            .into_iter()
+            .map(|build| {
+                let (score, _) = self.evaluate(config, &build, sims_per_eval, weights);
+                (build, score)
+            })
+            .collect();
+
+        for _ in 0..generations {
+            let mut next_gen: Vec<(Build, f64)> = Vec::with_capacity(population_size);
+
+            while next_gen.len() < population_size {
+                let parent_a = self.tournament_select(&population, tournament_size);
+                let parent_b = self.tournament_select(&population, tournament_size);
+                let child = self.crossover(parent_a, parent_b);
+                let child = self.mutate(&child, 2);
+                let child = self.repair(&child);
+                let (score, _) = self.evaluate(config, &child, sims_per_eval, weights);
+                next_gen.push((child, score));
+            }
+
+            population = next_gen;
+        }
+
+        population.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        population.truncate(top_n);
+
+        population.into_iter()
+            .map(|(build, _)| {
+                let (_, stats) = self.evaluate(config, &build, sims_per_eval, weights);
+                (build, stats)
+            })
+            .collect()
+    }
+
+    /// List every `PointChoice` that can legally be spent next from `build`.
+    fn legal_choices(&self, build: &Build) -> Vec<PointChoice> {
+        let (talents, attrs, equipment) = build;
+        let talent_spent: i32 = talents.values().sum();
+        let mut choices = Vec::new();
+
+        if talent_spent < self.talent_points {
+            for (name, info) in &self.talents {
+                if talents.get(name).copied().unwrap_or(0) < info.max {
+                    choices.push(PointChoice::Talent(name.clone()));
+                }
+            }
+        }
+
+        let attr_spent: i32 = attrs.iter()
+            .map(|(k, &v)| self.attributes.get(k).map(|i| i.cost * v).unwrap_or(0))
+            .sum();
+        let attr_remaining = self.attribute_points - attr_spent;
+        for name in self.attributes.keys() {
+            if self.can_add_attr_point(name, attrs, attr_remaining) {
+                choices.push(PointChoice::Attribute(name.clone()));
+            }
+        }
+
+        for (slot, items) in &self.equipment_slots {
+            if equipment.contains_key(slot) {
+                continue;
+            }
+            for item in items {
+                if self.can_equip_item(item, equipment) {
+                    choices.push(PointChoice::Equipment(slot.clone(), item.name.clone()));
+                }
+            }
+        }
+
+        choices
+    }
+
+    /// Apply a single `PointChoice` to `build`, returning the resulting child build.
+    fn apply_choice(&self, build: &Build, choice: &PointChoice) -> Build {
+        let (mut talents, mut attrs, mut equipment) = build.clone();
+        match choice {
+            PointChoice::Talent(name) => {
+                *talents.entry(name.clone()).or_insert(0) += 1;
+            }
+            PointChoice::Attribute(name) => {
+                *attrs.entry(name.clone()).or_insert(0) += 1;
+            }
+            PointChoice::Equipment(slot, item_name) => {
+                equipment.insert(slot.clone(), item_name.clone());
+            }
+        }
+        (talents, attrs, equipment)
+    }
+
+    /// Complete a partial build into a full one by random-walking the remaining budget and
+    /// filling any open equipment slots, reusing the same legality checks as a from-scratch
+    /// random walk.
+    fn rollout_complete(&self, build: &Build) -> Build {
+        let (mut talents, mut attrs, mut equipment) = build.clone();
+        let talent_spent: i32 = talents.values().sum();
+        self.fill_remaining_talents(&mut talents, self.talent_points - talent_spent);
+
+        let attr_spent: i32 = attrs.iter()
+            .map(|(k, &v)| self.attributes.get(k).map(|i| i.cost * v).unwrap_or(0))
+            .sum();
+        self.fill_remaining_attrs(&mut attrs, self.attribute_points - attr_spent);
+
+        self.fill_remaining_equipment(&mut equipment);
+
+        (talents, attrs, equipment)
+    }
+
+    /// Search for a strong point allocation using Monte-Carlo Tree Search.
+    ///
+    /// The root is the empty build; each edge spends one legal talent point, attribute point,
+    /// or equipment slot. Selection descends via UCB1 (`mean_stage + exploration *
+    /// sqrt(ln(N_parent)/N_child)`), expansion adds one untried legal choice, the rollout
+    /// completes the partial build through the existing random walk and scores it with a small
+    /// `run_and_aggregate` sample, and the resulting `avg_stage` is backpropagated up the
+    /// visited path. After `iterations`, the build is extracted by repeatedly descending into
+    /// the most-visited child.
+    pub fn search_mcts(&self, iterations: usize, exploration: f64, config: &BuildConfig) -> (Build, AggregatedStats) {
+        let empty_talents: HashMap<String, i32> = self.talents.keys().map(|k| (k.clone(), 0)).collect();
+        let empty_attrs: HashMap<String, i32> = self.attributes.keys().map(|k| (k.clone(), 0)).collect();
+        let empty_equipment: HashMap<String, String> = HashMap::new();
+        let root_build = (empty_talents, empty_attrs, empty_equipment);
+        let root_untried = self.legal_choices(&root_build);
+
+        let mut nodes: Vec<MctsNode> = vec![MctsNode {
+            build: root_build,
+            parent: None,
+            children: Vec::new(),
+            untried: root_untried,
+            visits: 0,
+            total_value: 0.0,
+        }];
+
+        const ROLLOUT_SIMS: usize = 8;
+
+        for _ in 0..iterations {
+            // Selection: descend via UCB1 until we find a node with untried moves or no children.
+            let mut current = 0usize;
+            while nodes[current].untried.is_empty() && !nodes[current].children.is_empty() {
+                current = self.ucb1_select(&nodes, current, exploration);
+            }
+
+            // Expansion: spend one untried legal point.
+            let mut leaf = current;
+            if !nodes[current].untried.is_empty() {
+                let mut rng = self.rng.borrow_mut();
+                let idx = rng.gen_range(0..nodes[current].untried.len());
+                let choice = nodes[current].untried.remove(idx);
+                let child_build = self.apply_choice(&nodes[current].build, &choice);
+                let child_untried = self.legal_choices(&child_build);
+                let child_index = nodes.len();
+                nodes.push(MctsNode {
+                    build: child_build,
+                    parent: Some(current),
+                    children: Vec::new(),
+                    untried: child_untried,
+                    visits: 0,
+                    total_value: 0.0,
+                });
+                nodes[current].children.push((choice, child_index));
+                leaf = child_index;
+            }
+
+            // Rollout: finish the build and score it.
+            let completed = self.rollout_complete(&nodes[leaf].build);
+            let (_, stats) = self.evaluate(config, &completed, ROLLOUT_SIMS, FitnessWeights::default());
+            let value = stats.avg_stage;
+
+            // Backpropagation.
+            let mut node_idx = Some(leaf);
+            while let Some(idx) = node_idx {
+                nodes[idx].visits += 1;
+                nodes[idx].total_value += value;
+                node_idx = nodes[idx].parent;
+            }
+        }
+
+        // Extraction: repeatedly descend into the most-visited child.
+        let mut current = 0usize;
+        while !nodes[current].children.is_empty() {
+            current = nodes[current].children.iter()
+                .max_by_key(|&&(_, child)| nodes[child].visits)
+                .map(|&(_, child)| child)
+                .unwrap();
+        }
+
+        let build = self.rollout_complete(&nodes[current].build);
+        let (_, stats) = self.evaluate(config, &build, ROLLOUT_SIMS.max(32), FitnessWeights::default());
+        (build, stats)
+    }
+
+    /// Select the child of `parent` maximizing the UCB1 score.
+    fn ucb1_select(&self, nodes: &[MctsNode], parent: usize, exploration: f64) -> usize {
+        let parent_visits = nodes[parent].visits as f64;
+        nodes[parent].children.iter()
+            .max_by(|&&(_, a), &&(_, b)| {
+                let score_a = self.ucb1_score(&nodes[a], parent_visits, exploration);
+                let score_b = self.ucb1_score(&nodes[b], parent_visits, exploration);
+                score_a.partial_cmp(&score_b).unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .map(|&(_, child)| child)
+            .unwrap()
+    }
+
+    fn ucb1_score(&self, node: &MctsNode, parent_visits: f64, exploration: f64) -> f64 {
+        if node.visits == 0 {
+            return f64::INFINITY;
+        }
+        let mean_stage = node.total_value / node.visits as f64;
+        mean_stage + exploration * (parent_visits.ln() / node.visits as f64).sqrt()
+    }
 }
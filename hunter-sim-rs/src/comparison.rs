@@ -0,0 +1,165 @@
+//! Head-to-head build comparison, on top of `AggregatedStats`.
+//!
+//! `main.rs`'s `print_results_table` already runs a directory of builds and prints a markdown
+//! table, but it only ever surfaces `avg_stage`/`std_stage`/survival rates and throws the
+//! `AggregatedStats` away afterward. `compare_builds` keeps the full stats for each build (so
+//! callers get the TMI and percentile fields too) and adds Pareto-dominance flagging, so two
+//! theorycrafted loadouts can be diffed in one command instead of eyeballed across separate runs.
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::BuildConfig;
+use crate::simulation::run_and_aggregate_seeded;
+use crate::stats::AggregatedStats;
+
+/// The "headline" metrics Pareto dominance is judged on: everything else in `AggregatedStats` is
+/// either a percentile breakdown of one of these or a secondary stat, so comparing on just these
+/// four keeps "does build A strictly beat build B" answerable at a glance.
+fn dominates(a: &AggregatedStats, b: &AggregatedStats) -> bool {
+    let at_least_as_good = a.avg_stage >= b.avg_stage
+        && a.avg_loot_per_hour >= b.avg_loot_per_hour
+        && a.boss5_survival >= b.boss5_survival
+        && a.avg_tmi <= b.avg_tmi;
+
+    let strictly_better = a.avg_stage > b.avg_stage
+        || a.avg_loot_per_hour > b.avg_loot_per_hour
+        || a.boss5_survival > b.boss5_survival
+        || a.avg_tmi < b.avg_tmi;
+
+    at_least_as_good && strictly_better
+}
+
+/// One build's row in a `ComparisonTable`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComparisonRow {
+    pub name: String,
+    pub avg_stage: f64,
+    pub boss1_survival: f64,
+    pub boss2_survival: f64,
+    pub boss3_survival: f64,
+    pub boss4_survival: f64,
+    pub boss5_survival: f64,
+    pub avg_loot_per_hour: f64,
+    pub avg_tmi: f64,
+    pub max_tmi: f64,
+    pub stage_p25: f64,
+    pub stage_median: f64,
+    pub stage_p75: f64,
+    pub stage_p95: f64,
+    /// Names of the other builds in this table that this row Pareto-dominates (better-or-equal on
+    /// every headline metric, strictly better on at least one).
+    pub dominates: Vec<String>,
+}
+
+/// A ranked head-to-head comparison across builds, sorted by descending `avg_stage`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComparisonTable {
+    pub rows: Vec<ComparisonRow>,
+}
+
+impl ComparisonTable {
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!(self)
+    }
+
+    /// Render as a human-readable, fixed-width aligned text grid (not markdown) - one row per
+    /// build, one column per field, widths computed from the longest entry in each column.
+    pub fn to_text_grid(&self) -> String {
+        let headers = [
+            "Build", "AvgStage", "Boss1%", "Boss2%", "Boss3%", "Boss4%", "Boss5%", "Loot/hr",
+            "AvgTMI", "MaxTMI", "P25", "Median", "P75", "P95", "Dominates",
+        ];
+
+        let cells: Vec<Vec<String>> = self
+            .rows
+            .iter()
+            .map(|row| {
+                vec![
+                    row.name.clone(),
+                    format!("{:.1}", row.avg_stage),
+                    format!("{:.1}", row.boss1_survival * 100.0),
+                    format!("{:.1}", row.boss2_survival * 100.0),
+                    format!("{:.1}", row.boss3_survival * 100.0),
+                    format!("{:.1}", row.boss4_survival * 100.0),
+                    format!("{:.1}", row.boss5_survival * 100.0),
+                    format!("{:.0}", row.avg_loot_per_hour),
+                    format!("{:.2}", row.avg_tmi),
+                    format!("{:.2}", row.max_tmi),
+                    format!("{:.1}", row.stage_p25),
+                    format!("{:.1}", row.stage_median),
+                    format!("{:.1}", row.stage_p75),
+                    format!("{:.1}", row.stage_p95),
+                    if row.dominates.is_empty() { "-".to_string() } else { row.dominates.join(", ") },
+                ]
+            })
+            .collect();
+
+        let mut widths: Vec<usize> = headers.iter().map(|h| h.len()).collect();
+        for row in &cells {
+            for (i, cell) in row.iter().enumerate() {
+                widths[i] = widths[i].max(cell.len());
+            }
+        }
+
+        let render_row = |cols: &[String]| -> String {
+            cols.iter()
+                .enumerate()
+                .map(|(i, cell)| format!("{:width$}", cell, width = widths[i]))
+                .collect::<Vec<_>>()
+                .join("  ")
+        };
+
+        let mut out = String::new();
+        out.push_str(&render_row(&headers.iter().map(|h| h.to_string()).collect::<Vec<_>>()));
+        out.push('\n');
+        out.push_str(&widths.iter().map(|w| "-".repeat(*w)).collect::<Vec<_>>().join("  "));
+        out.push('\n');
+        for row in &cells {
+            out.push_str(&render_row(row));
+            out.push('\n');
+        }
+        out
+    }
+}
+
+/// Run every `(name, BuildConfig)` in `builds` for `runs_per_build` simulations each (seeded for
+/// reproducibility), and assemble a ranked `ComparisonTable` with Pareto-dominance flags.
+pub fn compare_builds(builds: &[(String, BuildConfig)], runs_per_build: usize, seed: u64, parallel: bool) -> ComparisonTable {
+    let stats: Vec<(String, AggregatedStats)> = builds
+        .iter()
+        .map(|(name, config)| (name.clone(), run_and_aggregate_seeded(config, runs_per_build, seed, parallel)))
+        .collect();
+
+    let mut rows: Vec<ComparisonRow> = stats
+        .iter()
+        .map(|(name, s)| {
+            let dominates = stats
+                .iter()
+                .filter(|(other_name, other)| other_name != name && dominates(s, other))
+                .map(|(other_name, _)| other_name.clone())
+                .collect();
+
+            ComparisonRow {
+                name: name.clone(),
+                avg_stage: s.avg_stage,
+                boss1_survival: s.boss1_survival,
+                boss2_survival: s.boss2_survival,
+                boss3_survival: s.boss3_survival,
+                boss4_survival: s.boss4_survival,
+                boss5_survival: s.boss5_survival,
+                avg_loot_per_hour: s.avg_loot_per_hour,
+                avg_tmi: s.avg_tmi,
+                max_tmi: s.max_tmi,
+                stage_p25: s.stage_distribution.p25,
+                stage_median: s.stage_distribution.median,
+                stage_p75: s.stage_distribution.p75,
+                stage_p95: s.stage_distribution.p95,
+                dominates,
+            }
+        })
+        .collect();
+
+    rows.sort_by(|a, b| b.avg_stage.partial_cmp(&a.avg_stage).unwrap_or(std::cmp::Ordering::Equal));
+
+    ComparisonTable { rows }
+}
@@ -1,9 +1,14 @@
 //! Configuration structures for loading build YAML files
 
+use crate::enemy::{Element, RaceSizeBonus};
 use serde::{Deserialize, Deserializer, Serialize};
 use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
+use std::str::FromStr;
+
+/// Highest level accepted by `validate`.
+const MAX_LEVEL: i32 = 1000;
 
 /// The type of hunter
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
@@ -43,7 +48,7 @@ pub struct Meta {
 /// Supports both formats:
 /// 1. { "meta": { "hunter": "Borge", "level": 69 }, ... }  (original YAML format)
 /// 2. { "hunter": "Borge", "level": 69, ... }             (GUI JSON format)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct BuildConfig {
     // Support both nested meta and flat format
     #[serde(default)]
@@ -69,6 +74,112 @@ pub struct BuildConfig {
     pub gadgets: HashMap<String, i32>,
     #[serde(default)]
     pub bonuses: HashMap<String, serde_json::Value>,
+
+    /// The hunter's base elemental damage type (e.g. "fire", "cold", "radiation", "physical").
+    /// Specific attunement attributes can still shift this at build-creation time.
+    #[serde(default)]
+    pub damage_type: Option<String>,
+    /// Per-stage elemental weaknesses, keyed by stage number as a string. A weakness doubles
+    /// damage of the matching `damage_type`.
+    #[serde(default)]
+    pub enemy_weaknesses: HashMap<String, Vec<String>>,
+    /// Per-stage elemental immunities, keyed by stage number as a string. An immunity zeroes
+    /// damage of the matching `damage_type`.
+    #[serde(default)]
+    pub enemy_immunities: HashMap<String, Vec<String>>,
+    /// Overrides the stage-cycled defensive element (see `enemy::assign_element`) with a fixed
+    /// one for every enemy in the run, for builds that want to test a single matchup against the
+    /// `ATTR_FIX_TABLE` affinity rather than the usual per-stage rotation. An active
+    /// `trample`/`decay` mod takes precedence over it.
+    #[serde(default)]
+    pub forced_enemy_element: Option<String>,
+    /// Custom attack/defense affinity multipliers, replacing the built-in `ATTR_FIX_TABLE`
+    /// entirely when set. Must be square with one row/column per `Element` variant (see
+    /// `Element::COUNT`), indexed `[attack][defense]`; `BuildConfig::validate` rejects anything
+    /// else. Lets a build price in a matchup against the `trample`/`decay` mods as data instead
+    /// of a scattered boolean check.
+    #[serde(default)]
+    pub effectiveness_table: Option<Vec<Vec<f64>>>,
+    /// How many secondary enemies a hunter attack splashes onto, in addition to its primary
+    /// target, when a regular (non-boss) stage has more than one enemy alive.
+    #[serde(default)]
+    pub splash_count: Option<i32>,
+    /// Fraction of an attack's damage dealt to each splash target (the primary target always
+    /// takes the full hit).
+    #[serde(default)]
+    pub splash_fraction: Option<f64>,
+    /// Master RNG seed for reproducible runs. When set, `run_and_aggregate` derives each
+    /// simulation's seed as `seed ^ splitmix64(index)`, so the i-th result is byte-identical
+    /// across machines and regardless of parallel vs. sequential execution.
+    #[serde(default)]
+    pub seed: Option<u64>,
+    /// Global loot/XP rate knobs and the over-leveled farming penalty; see `RateConfig`.
+    /// `None` behaves exactly like `RateConfig::default()` (rate 1.0, no level penalty).
+    #[serde(default)]
+    pub rate_config: Option<RateConfig>,
+    /// This build's bonus damage against specific enemy race/size categories, applied via
+    /// `Enemy::take_damage_with_reflect`. `None` behaves like `RaceSizeBonus::default()` (no
+    /// bonus).
+    #[serde(default)]
+    pub race_size_bonus: Option<RaceSizeBonus>,
+}
+
+/// Global loot/XP rate modifiers, folded into `Hunter::calculate_loot`. `level_penalty_curve`
+/// maps `(level - current_stage)` gap buckets to a multiplier - e.g. `[(10, 1.0), (20, 0.9)]`
+/// means within a 10-level gap deals full loot, linearly ramping down to 0.9 by a 20-level gap.
+/// Buckets are interpolated linearly and clamped at the endpoints, so an empty curve (the
+/// default) is equivalent to a flat 1.0 penalty at every gap - i.e. no behavior change.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RateConfig {
+    #[serde(default = "default_rate")]
+    pub loot_rate: f64,
+    #[serde(default = "default_rate")]
+    pub xp_rate: f64,
+    #[serde(default)]
+    pub level_penalty_curve: Vec<(i32, f64)>,
+}
+
+fn default_rate() -> f64 {
+    1.0
+}
+
+impl Default for RateConfig {
+    fn default() -> Self {
+        Self {
+            loot_rate: 1.0,
+            xp_rate: 1.0,
+            level_penalty_curve: Vec::new(),
+        }
+    }
+}
+
+impl RateConfig {
+    /// Linearly interpolate the level-gap penalty curve at `gap`, clamped at the endpoints.
+    /// Returns 1.0 when the curve is empty, so farming-rate tuning is opt-in.
+    pub fn level_penalty(&self, gap: i32) -> f64 {
+        if self.level_penalty_curve.is_empty() {
+            return 1.0;
+        }
+        let mut buckets = self.level_penalty_curve.clone();
+        buckets.sort_by_key(|(k, _)| *k);
+        let gap = gap as f64;
+
+        if gap <= buckets[0].0 as f64 {
+            return buckets[0].1;
+        }
+        if gap >= buckets[buckets.len() - 1].0 as f64 {
+            return buckets[buckets.len() - 1].1;
+        }
+        for pair in buckets.windows(2) {
+            let (k0, v0) = pair[0];
+            let (k1, v1) = pair[1];
+            if gap >= k0 as f64 && gap <= k1 as f64 {
+                let t = (gap - k0 as f64) / (k1 as f64 - k0 as f64);
+                return v0 + t * (v1 - v0);
+            }
+        }
+        buckets[buckets.len() - 1].1
+    }
 }
 
 impl BuildConfig {
@@ -90,26 +201,35 @@ impl BuildConfig {
         }
     }
     
-    /// Load a build configuration from a YAML file
+    /// Load a build configuration from a JSON, YAML, or TOML file, dispatching on extension
+    /// (`.json`, `.toml`, else YAML).
     pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn std::error::Error>> {
         let content = fs::read_to_string(&path)?;
         let path_str = path.as_ref().to_string_lossy().to_lowercase();
-        
-        // Check if it's JSON or YAML
+
         if path_str.ends_with(".json") {
             let config: BuildConfig = serde_json::from_str(&content)?;
             Ok(config)
+        } else if path_str.ends_with(".toml") {
+            Self::from_toml(&content)
         } else {
             let config: BuildConfig = serde_yaml::from_str(&content)?;
             Ok(config)
         }
     }
-    
+
     /// Load from JSON string (for Python interop)
     pub fn from_json(json: &str) -> Result<Self, Box<dyn std::error::Error>> {
         let config: BuildConfig = serde_json::from_str(json)?;
         Ok(config)
     }
+
+    /// Load from a TOML string. Round-trips the same flat-vs-nested `meta` handling and
+    /// `#[serde(default)]` maps as `from_json`/YAML, so builds can be hand-authored in TOML.
+    pub fn from_toml(toml_str: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let config: BuildConfig = toml::from_str(toml_str)?;
+        Ok(config)
+    }
     
     /// Get a stat value with default
     pub fn get_stat(&self, key: &str) -> i32 {
@@ -140,4 +260,296 @@ impl BuildConfig {
     pub fn get_gem(&self, key: &str) -> i32 {
         *self.gems.get(key).unwrap_or(&0)
     }
+
+    /// Get the configured elemental weaknesses for a given stage, if any.
+    pub fn get_enemy_weaknesses(&self, stage: i32) -> Vec<String> {
+        self.enemy_weaknesses.get(&stage.to_string()).cloned().unwrap_or_default()
+    }
+
+    /// Get the configured elemental immunities for a given stage, if any.
+    pub fn get_enemy_immunities(&self, stage: i32) -> Vec<String> {
+        self.enemy_immunities.get(&stage.to_string()).cloned().unwrap_or_default()
+    }
+
+    /// Cross-check this config against its hunter's known keys and report anything that looks
+    /// like a typo rather than a deliberate value: unknown `stats`/`talents`/`attributes`/
+    /// `inscryptions`/`relics`/`gems` keys (which otherwise silently resolve to 0 via
+    /// `get_stat`/`get_talent`/etc.), a key duplicated across sections, an out-of-range `level`,
+    /// and `bonuses` entries whose value doesn't match their expected shape.
+    pub fn validate(&self) -> Result<(), Vec<ConfigIssue>> {
+        let mut issues = Vec::new();
+        let known = KnownKeys::for_hunter(self.get_hunter_type());
+
+        check_unknown_keys(&self.stats, "stats", known.stats, &mut issues);
+        check_unknown_keys(&self.talents, "talents", known.talents, &mut issues);
+        check_unknown_keys(&self.attributes, "attributes", known.attributes, &mut issues);
+        check_unknown_keys(&self.inscryptions, "inscryptions", known.inscryptions, &mut issues);
+        check_unknown_keys(&self.relics, "relics", known.relics, &mut issues);
+        check_unknown_keys(&self.gems, "gems", known.gems, &mut issues);
+
+        check_duplicate_keys(
+            &[
+                ("stats", &self.stats),
+                ("talents", &self.talents),
+                ("attributes", &self.attributes),
+                ("inscryptions", &self.inscryptions),
+                ("relics", &self.relics),
+                ("gems", &self.gems),
+            ],
+            &mut issues,
+        );
+
+        let level = self.get_level();
+        if !(1..=MAX_LEVEL).contains(&level) {
+            issues.push(ConfigIssue {
+                section: "meta".to_string(),
+                key: "level".to_string(),
+                message: format!("level {} is out of range (expected 1..={})", level, MAX_LEVEL),
+            });
+        }
+
+        if let Some(table) = &self.effectiveness_table {
+            check_effectiveness_table(table, &mut issues);
+        }
+
+        for (key, value) in &self.bonuses {
+            if let Some(expected) = bonus_conversion(key) {
+                if let Err(reason) = expected.coerce(value) {
+                    issues.push(ConfigIssue {
+                        section: "bonuses".to_string(),
+                        key: key.clone(),
+                        message: format!("{} (expected {})", reason, expected),
+                    });
+                }
+            }
+        }
+
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(issues)
+        }
+    }
+}
+
+/// A single problem found by `BuildConfig::validate`, naming the section/key it came from so a
+/// GUI can point directly at the offending field instead of a generic "invalid config" message.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigIssue {
+    pub section: String,
+    pub key: String,
+    pub message: String,
+}
+
+/// The set of keys each `create_*` builder actually reads via `get_stat`/`get_talent`/etc. Kept
+/// here (rather than derived from `hunter.rs`) since it describes the config's shape, not the
+/// hunter's combat behavior.
+struct KnownKeys {
+    stats: &'static [&'static str],
+    talents: &'static [&'static str],
+    attributes: &'static [&'static str],
+    inscryptions: &'static [&'static str],
+    relics: &'static [&'static str],
+    gems: &'static [&'static str],
+}
+
+const COMMON_STATS: &[&str] = &[
+    "damage_reduction", "effect_chance", "evade_chance", "hp", "power", "regen",
+    "special_chance", "special_damage", "speed",
+];
+
+impl KnownKeys {
+    fn for_hunter(hunter: HunterType) -> Self {
+        match hunter {
+            HunterType::Borge => KnownKeys {
+                stats: COMMON_STATS,
+                talents: &[
+                    "death_is_my_companion", "fires_of_war", "impeccable_impacts",
+                    "life_of_the_hunt", "omen_of_defeat", "presence_of_god", "unfair_advantage",
+                ],
+                attributes: &[
+                    "atlas_protocol", "book_of_baal", "born_for_battle", "essence_of_ylith",
+                    "explosive_punches", "helltouch_barrier", "lifedrain_inhalers",
+                    "soul_of_ares", "soul_of_athena", "soul_of_hermes", "soul_of_the_minotaur",
+                    "spartan_lineage", "superior_sensors", "timeless_mastery", "weakspot_analysis",
+                    "cold_attunement", "fire_attunement", "radiation_attunement",
+                ],
+                inscryptions: &["i3", "i4", "i11", "i13", "i14", "i23", "i24", "i27", "i44", "i60"],
+                relics: &["disk_of_dawn", "long_range_artillery_crawler"],
+                gems: &["creation_node_#1", "creation_node_#2", "creation_node_#3", "innovation_node_#3"],
+            },
+            HunterType::Ozzy => KnownKeys {
+                stats: COMMON_STATS,
+                talents: &[
+                    "crippling_shots", "death_is_my_companion", "echo_bullets", "echo_location",
+                    "life_of_the_hunt", "multistriker", "omen_of_decay", "omen_of_defeat",
+                    "presence_of_god", "thousand_needles", "tricksters_boon", "unfair_advantage",
+                ],
+                attributes: &[
+                    "blessings_of_the_cat", "blessings_of_the_scarab", "blessings_of_the_sisters",
+                    "cycle_of_death", "dance_of_dashes", "deal_with_death", "exo_piercers",
+                    "extermination_protocol", "gift_of_medusa", "living_off_the_land",
+                    "shimmering_scorpion", "soul_of_snek", "timeless_mastery", "vectid_elixir",
+                    "wings_of_ibu",
+                    "cold_attunement", "fire_attunement", "radiation_attunement",
+                ],
+                inscryptions: &["i31", "i32", "i36", "i37", "i40"],
+                relics: &["bee_gone_companion_drone", "disk_of_dawn"],
+                gems: &["innovation_node_#3"],
+            },
+            HunterType::Knox => KnownKeys {
+                stats: &[
+                    "block_chance", "charge_chance", "charge_gained", "damage_reduction",
+                    "effect_chance", "hp", "power", "projectiles_per_salvo", "regen", "reload_time",
+                ],
+                talents: &[
+                    "calypsos_advantage", "death_is_my_companion", "finishing_move",
+                    "ghost_bullets", "omen_of_defeat", "presence_of_god", "unfair_advantage",
+                ],
+                attributes: &[
+                    "a_pirates_life_for_knox", "fortification_elixir", "release_the_kraken",
+                    "serious_efficiency", "shield_of_poseidon", "soul_amplification",
+                    "space_pirate_armory", "timeless_mastery",
+                    "cold_attunement", "fire_attunement", "radiation_attunement",
+                ],
+                inscryptions: &[],
+                relics: &["disk_of_dawn"],
+                gems: &[],
+            },
+        }
+    }
+}
+
+fn check_unknown_keys(
+    map: &HashMap<String, i32>,
+    section: &str,
+    known: &[&str],
+    issues: &mut Vec<ConfigIssue>,
+) {
+    for key in map.keys() {
+        if !known.contains(&key.as_str()) {
+            issues.push(ConfigIssue {
+                section: section.to_string(),
+                key: key.clone(),
+                message: format!("unknown {} key for this hunter", section),
+            });
+        }
+    }
+}
+
+fn check_duplicate_keys(sections: &[(&str, &HashMap<String, i32>)], issues: &mut Vec<ConfigIssue>) {
+    let mut seen_in: HashMap<&str, Vec<&str>> = HashMap::new();
+    for (section, map) in sections {
+        for key in map.keys() {
+            seen_in.entry(key.as_str()).or_default().push(section);
+        }
+    }
+    for (key, sections) in seen_in {
+        if sections.len() > 1 {
+            issues.push(ConfigIssue {
+                section: sections.join(", "),
+                key: key.to_string(),
+                message: "key is set in more than one section; likely misplaced".to_string(),
+            });
+        }
+    }
+}
+
+/// Reject an `effectiveness_table` that isn't square, or isn't sized for `Element::COUNT`, since
+/// `resolve_affinity` indexes it directly by `Element::index()` with no bounds checking.
+fn check_effectiveness_table(table: &[Vec<f64>], issues: &mut Vec<ConfigIssue>) {
+    let rows = table.len();
+    if rows != Element::COUNT || table.iter().any(|row| row.len() != rows) {
+        issues.push(ConfigIssue {
+            section: "effectiveness_table".to_string(),
+            key: "effectiveness_table".to_string(),
+            message: format!(
+                "table must be square with {} rows/columns, one per Element variant",
+                Element::COUNT
+            ),
+        });
+    }
+}
+
+/// The expected shape of a `bonuses` value, so string-encoded values sent by the GUI (e.g.
+/// `"1500"`, `"true"`) can be coerced to what the declared type actually needs instead of being
+/// silently misread downstream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Conversion {
+    Integer,
+    Float,
+    Boolean,
+    /// Unix timestamp in seconds, as either a bare integer or an integer-valued string.
+    Timestamp,
+}
+
+impl std::fmt::Display for Conversion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Conversion::Integer => "integer",
+            Conversion::Float => "float",
+            Conversion::Boolean => "boolean",
+            Conversion::Timestamp => "timestamp",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl FromStr for Conversion {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "integer" | "int" => Ok(Conversion::Integer),
+            "float" => Ok(Conversion::Float),
+            "boolean" | "bool" => Ok(Conversion::Boolean),
+            "timestamp" => Ok(Conversion::Timestamp),
+            other => Err(format!("unknown conversion type: {}", other)),
+        }
+    }
+}
+
+impl Conversion {
+    /// Check (without mutating the config) that `value` either already has this shape or is a
+    /// string that parses to it, the way values round-tripped through the GUI's JSON layer do.
+    fn coerce(self, value: &serde_json::Value) -> Result<(), String> {
+        use serde_json::Value;
+        match (self, value) {
+            (Conversion::Integer, Value::Number(n)) if n.is_i64() || n.is_u64() => Ok(()),
+            (Conversion::Integer, Value::String(s)) => {
+                s.parse::<i64>().map(|_| ()).map_err(|_| format!("\"{}\" is not an integer", s))
+            }
+            (Conversion::Integer, other) => Err(format!("{} is not an integer", other)),
+
+            (Conversion::Float, Value::Number(_)) => Ok(()),
+            (Conversion::Float, Value::String(s)) => {
+                s.parse::<f64>().map(|_| ()).map_err(|_| format!("\"{}\" is not a float", s))
+            }
+            (Conversion::Float, other) => Err(format!("{} is not a float", other)),
+
+            (Conversion::Boolean, Value::Bool(_)) => Ok(()),
+            (Conversion::Boolean, Value::String(s)) => {
+                s.parse::<bool>().map(|_| ()).map_err(|_| format!("\"{}\" is not a boolean", s))
+            }
+            (Conversion::Boolean, other) => Err(format!("{} is not a boolean", other)),
+
+            (Conversion::Timestamp, Value::Number(n)) if n.is_i64() || n.is_u64() => Ok(()),
+            (Conversion::Timestamp, Value::String(s)) => {
+                s.parse::<i64>().map(|_| ()).map_err(|_| format!("\"{}\" is not a unix timestamp", s))
+            }
+            (Conversion::Timestamp, other) => Err(format!("{} is not a unix timestamp", other)),
+        }
+    }
+}
+
+/// The declared type for a handful of well-known GUI-sent `bonuses` keys. Keys outside this list
+/// are left unchecked, since `bonuses` is otherwise an intentionally freeform passthrough map.
+fn bonus_conversion(key: &str) -> Option<Conversion> {
+    match key {
+        "loot_multiplier" | "xp_multiplier" => Some(Conversion::Float),
+        "bonus_stage_skip" => Some(Conversion::Integer),
+        "double_loot_event" => Some(Conversion::Boolean),
+        "event_ends_at" => Some(Conversion::Timestamp),
+        _ => None,
+    }
 }
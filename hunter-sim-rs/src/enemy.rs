@@ -2,6 +2,321 @@
 
 use crate::config::HunterType;
 use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+const ELEMENT_COUNT: usize = 6;
+
+/// Elemental type used by the attack/defense affinity table (modeled on Hercules'
+/// `attr_fix_table[level][atk_ele][def_ele]`). `Trample`/`Decay` are mod-class defense types
+/// rather than true elements - an enemy takes on one of them instead of its usual stage-cycled
+/// element when the run's `trample`/`decay` mod is active (see `BuildConfig.mods`), so a build's
+/// `EffectivenessTable` override can price in a matchup against those mods the same way it would
+/// against Fire or Cold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Element {
+    Physical,
+    Fire,
+    Cold,
+    Radiation,
+    Trample,
+    Decay,
+}
+
+impl Element {
+    /// Number of `Element` variants - the expected dimension of a custom `EffectivenessTable`.
+    pub const COUNT: usize = ELEMENT_COUNT;
+
+    /// Parse a damage-type string (as used by `BuildConfig`/`Hunter::damage_type`) into an
+    /// `Element`, defaulting to `Physical` for anything unrecognized.
+    pub fn from_str(s: &str) -> Self {
+        match s {
+            "fire" => Element::Fire,
+            "cold" => Element::Cold,
+            "radiation" => Element::Radiation,
+            "trample" => Element::Trample,
+            "decay" => Element::Decay,
+            _ => Element::Physical,
+        }
+    }
+
+    fn index(self) -> usize {
+        match self {
+            Element::Physical => 0,
+            Element::Fire => 1,
+            Element::Cold => 2,
+            Element::Radiation => 3,
+            Element::Trample => 4,
+            Element::Decay => 5,
+        }
+    }
+}
+
+/// `ATTR_FIX_TABLE[level - 1][attack][defense]` gives the percentage of damage dealt (100 =
+/// unaffected). Physical is neutral against everything. The Fire -> Cold -> Radiation -> Fire
+/// cycle is a weakness that strengthens with `element_level`, while hitting an enemy with its
+/// own defense element is increasingly resisted, turning into outright healing (a negative
+/// percentage) at level 4. Trample/Decay aren't part of that cycle and stay neutral (100) in the
+/// built-in table; a build that cares about them supplies a custom `EffectivenessTable` instead.
+const ATTR_FIX_TABLE: [[[i32; ELEMENT_COUNT]; ELEMENT_COUNT]; 4] = [
+    // Level 1
+    [
+        [100, 100, 100, 100, 100, 100],
+        [100, 75, 125, 100, 100, 100],
+        [100, 100, 75, 125, 100, 100],
+        [100, 125, 100, 75, 100, 100],
+        [100, 100, 100, 100, 100, 100],
+        [100, 100, 100, 100, 100, 100],
+    ],
+    // Level 2
+    [
+        [100, 100, 100, 100, 100, 100],
+        [100, 50, 150, 100, 100, 100],
+        [100, 100, 50, 150, 100, 100],
+        [100, 150, 100, 50, 100, 100],
+        [100, 100, 100, 100, 100, 100],
+        [100, 100, 100, 100, 100, 100],
+    ],
+    // Level 3
+    [
+        [100, 100, 100, 100, 100, 100],
+        [100, 25, 175, 100, 100, 100],
+        [100, 100, 25, 175, 100, 100],
+        [100, 175, 100, 25, 100, 100],
+        [100, 100, 100, 100, 100, 100],
+        [100, 100, 100, 100, 100, 100],
+    ],
+    // Level 4
+    [
+        [100, 100, 100, 100, 100, 100],
+        [100, -50, 200, 100, 100, 100],
+        [100, 100, -50, 200, 100, 100],
+        [100, 200, 100, -50, 100, 100],
+        [100, 100, 100, 100, 100, 100],
+        [100, 100, 100, 100, 100, 100],
+    ],
+];
+
+/// Resolve the attack/defense affinity multiplier (1.0 = unaffected) for an attack of
+/// `attack_element` against a defender of `defense_element` at the given `element_level` (1-4).
+/// `override_table`, when present (from `BuildConfig::effectiveness_table`), replaces the
+/// built-in `ATTR_FIX_TABLE` entirely so a build can define its own matchups, including against
+/// the `Trample`/`Decay` mod classes.
+fn resolve_affinity(
+    element_level: i32,
+    attack_element: Element,
+    defense_element: Element,
+    override_table: Option<&Vec<Vec<f64>>>,
+) -> f64 {
+    if let Some(table) = override_table {
+        return table[attack_element.index()][defense_element.index()];
+    }
+    let level_idx = (element_level.clamp(1, 4) - 1) as usize;
+    ATTR_FIX_TABLE[level_idx][attack_element.index()][defense_element.index()] as f64 / 100.0
+}
+
+/// Assign a defending element and element level (1-4) for an enemy, varying by stage, hunter
+/// type, and boss status. Element level ramps up every 250 stages so the weakness/resist swings
+/// in `ATTR_FIX_TABLE` grow more pronounced deeper into a run.
+fn assign_element(stage: i32, hunter_type: HunterType, is_boss: bool) -> (Element, i32) {
+    let element_level = (stage / 250 + 1).min(4);
+
+    let type_offset = match hunter_type {
+        HunterType::Borge => 0,
+        HunterType::Ozzy => 1,
+        HunterType::Knox => 2,
+    };
+    let boss_offset = if is_boss { 1 } else { 0 };
+
+    let defense_element = match (stage / 100 + type_offset + boss_offset) % 4 {
+        0 => Element::Physical,
+        1 => Element::Fire,
+        2 => Element::Cold,
+        _ => Element::Radiation,
+    };
+
+    (defense_element, element_level)
+}
+
+/// Base fraction of damage dealt that heals the enemy, before enrage scaling. Bosses drain
+/// more aggressively than regular enemies, which only start draining at stage 300+.
+fn base_life_drain(stage: i32, is_boss: bool) -> f64 {
+    if is_boss {
+        0.08
+    } else if stage >= 300 {
+        0.03
+    } else {
+        0.0
+    }
+}
+
+/// Kind of status ailment an enemy (or hunter-side buff) can carry, modeled on Hercules' `SC_*`
+/// states. `EmpoweredRegen`/`EmpoweredBlockRegen`/`TricksterCharges`/`DecayStacks`/`HundredSouls`
+/// are hunter-only (see `Hunter::statuses`); they're folded into this enum rather than a separate
+/// one so both sides share one apply/expire/check vocabulary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusKind {
+    Poison,
+    Burn,
+    Slow,
+    Weaken,
+    Stun,
+    EmpoweredRegen,
+    EmpoweredBlockRegen,
+    /// Ozzy's Trickster's Boon: stacking evade-proc charges, consumed one at a time.
+    TricksterCharges,
+    /// Ozzy's Crippling Shots decay stacks: consumed all at once as bonus damage on next hit.
+    DecayStacks,
+    /// Knox's Hundred Souls stacking power buff (gained via Calypso's Advantage), capped by
+    /// `max_stacks`.
+    HundredSouls,
+}
+
+/// How re-applying an already-active effect of the same kind behaves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StackingRule {
+    /// Refresh `end_time`/`magnitude` only; `stacks` stays pinned at 1.
+    RefreshDuration,
+    /// Refresh `end_time`/`magnitude` and add one stack, capped at `max_stacks`.
+    AddStack,
+}
+
+/// An active status effect on an enemy or hunter: `magnitude` is the per-tick damage for
+/// Poison/Burn, or the multiplier strength for Slow/Weaken; `next_tick` is only consulted for
+/// ticking kinds. `stacks`/`max_stacks` let the same slot represent a stacking counter (e.g.
+/// decay/trickster charges) instead of a single on/off buff.
+#[derive(Debug, Clone)]
+pub struct StatusEffect {
+    pub kind: StatusKind,
+    pub end_time: f64,
+    pub magnitude: f64,
+    pub next_tick: f64,
+    pub tick_interval: f64,
+    pub stacks: i32,
+    pub max_stacks: i32,
+}
+
+/// Result of a damage application that can also reflect a share of it back at the attacker.
+#[derive(Debug, Clone, Copy)]
+pub struct DamageResult {
+    pub applied: f64,
+    /// Net bonus/penalty attributable to the `ATTR_FIX_TABLE` affinity matchup, isolated from
+    /// every other multiplier.
+    pub elemental_delta: f64,
+    pub reflected: f64,
+}
+
+/// Which ticking damage-over-time effect a `dots` slot belongs to, so applying the same kind
+/// again refreshes/stacks instead of layering a second independent timer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DotKind {
+    Bleed,
+    Poison,
+    Burn,
+}
+
+/// A ticking damage-over-time effect, modeled on SimulationCraft's `dot_t` (black_arrow /
+/// barbed_shot / explosive_shot): each tick is its own scheduled event rather than something
+/// polled on a fixed cadence, so `ticks_remaining` only advances when `tick_dot` is actually
+/// called for this slot.
+#[derive(Debug, Clone)]
+pub struct ActiveDot {
+    pub damage_per_tick: f64,
+    pub ticks_remaining: u32,
+    pub tick_interval: f64,
+    pub refreshable: bool,
+    pub source: DotKind,
+}
+
+/// Broad creature category, modeled on Renewal rAthena's race-based damage modifiers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnemyRace {
+    Formless,
+    Beast,
+    Construct,
+    Demon,
+    Boss,
+}
+
+/// Size class, modeled on Renewal rAthena's size-based damage modifiers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnemySize {
+    Small,
+    Medium,
+    Large,
+}
+
+/// A hunter build's bonus damage against specific race/size categories (0.0 = no bonus), resolved
+/// from `BuildConfig::race_size_bonus` and applied via `Enemy::take_damage_with_reflect`.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct RaceSizeBonus {
+    #[serde(default)]
+    pub vs_formless: f64,
+    #[serde(default)]
+    pub vs_beast: f64,
+    #[serde(default)]
+    pub vs_construct: f64,
+    #[serde(default)]
+    pub vs_demon: f64,
+    #[serde(default)]
+    pub vs_boss: f64,
+    #[serde(default)]
+    pub vs_small: f64,
+    #[serde(default)]
+    pub vs_medium: f64,
+    #[serde(default)]
+    pub vs_large: f64,
+}
+
+impl RaceSizeBonus {
+    /// Resolve the combined (additive) bonus multiplier against `race`/`size`, e.g. 0.15 means
+    /// +15% damage.
+    pub fn resolve(&self, race: EnemyRace, size: EnemySize) -> f64 {
+        let race_bonus = match race {
+            EnemyRace::Formless => self.vs_formless,
+            EnemyRace::Beast => self.vs_beast,
+            EnemyRace::Construct => self.vs_construct,
+            EnemyRace::Demon => self.vs_demon,
+            EnemyRace::Boss => self.vs_boss,
+        };
+        let size_bonus = match size {
+            EnemySize::Small => self.vs_small,
+            EnemySize::Medium => self.vs_medium,
+            EnemySize::Large => self.vs_large,
+        };
+        1.0 + race_bonus + size_bonus
+    }
+}
+
+/// Assign a race/size classification for an enemy, varying by stage, hunter type, and boss
+/// status. Bosses are always `EnemyRace::Boss` and `EnemySize::Large`; regular enemies cycle
+/// through the remaining races/sizes by stage so different builds' race/size bonuses matter
+/// across a run instead of against a single undifferentiated roster.
+fn assign_race_size(stage: i32, hunter_type: HunterType, is_boss: bool) -> (EnemyRace, EnemySize) {
+    if is_boss {
+        return (EnemyRace::Boss, EnemySize::Large);
+    }
+
+    let type_offset = match hunter_type {
+        HunterType::Borge => 0,
+        HunterType::Ozzy => 1,
+        HunterType::Knox => 2,
+    };
+
+    let race = match (stage / 50 + type_offset) % 4 {
+        0 => EnemyRace::Formless,
+        1 => EnemyRace::Beast,
+        2 => EnemyRace::Construct,
+        _ => EnemyRace::Demon,
+    };
+
+    let size = match (stage / 75 + type_offset) % 3 {
+        0 => EnemySize::Small,
+        1 => EnemySize::Medium,
+        _ => EnemySize::Large,
+    };
+
+    (race, size)
+}
 
 /// A regular enemy in combat
 #[derive(Debug, Clone)]
@@ -20,13 +335,43 @@ pub struct Enemy {
     pub speed: f64,
     pub base_speed: f64,  // Store base speed for enrage calculations
     pub is_boss: bool,
-    pub is_stunned: bool,
-    pub stun_end_time: f64,
     // Boss-specific
     pub enrage_stacks: i32,
     pub has_secondary: bool,
     pub speed2: f64,
     pub base_speed2: f64,
+    // Elemental matchups - damage types the hunter is in `weaknesses` deal 2x, types in
+    // `immunities` deal 0x, everything else is unaffected.
+    pub weaknesses: Vec<String>,
+    pub immunities: Vec<String>,
+    /// This enemy's element/level for the `ATTR_FIX_TABLE` affinity lookup, independent of the
+    /// config-driven `weaknesses`/`immunities` override above.
+    pub defense_element: Element,
+    pub element_level: i32,
+    /// Custom attack/defense affinity multipliers from `BuildConfig::effectiveness_table`,
+    /// overriding the built-in `ATTR_FIX_TABLE` when present; see `resolve_affinity`.
+    pub effectiveness_table: Option<Vec<Vec<f64>>>,
+    /// Active Poison/Burn/Slow/Weaken/Stun effects; see `apply_status`/`tick_statuses`.
+    pub statuses: Vec<StatusEffect>,
+    /// Multiplier applied on top of `speed`/`speed2` by active Slow effects (1.0 = unaffected).
+    pub slow_factor: f64,
+    /// Multiplier applied to attack power by active Weaken effects (1.0 = unaffected).
+    pub weaken_factor: f64,
+    /// Fraction of damage dealt by this enemy's attacks that heals it back, mirroring
+    /// Hercules' `battle_drain`.
+    pub life_drain: f64,
+    /// Chance per incoming hit to reflect a share of it back at the attacker (bosses only).
+    pub reflect_chance: f64,
+    /// Fraction of the damage that got through `damage_reduction` which gets reflected.
+    pub reflect_fraction: f64,
+    /// Creature category and size class, used to resolve a hunter build's bonus-vs-race/size
+    /// damage modifiers.
+    pub race: EnemyRace,
+    pub size: EnemySize,
+    /// Active bleed/poison/burn ticks; see `apply_dot`/`tick_dot`. Indexed by `Action::DotTick`,
+    /// so slots are refreshed in place rather than removed, keeping indices stable for the
+    /// lifetime of this enemy instance.
+    pub dots: Vec<ActiveDot>,
 }
 
 impl Enemy {
@@ -83,9 +428,30 @@ impl Enemy {
 
     /// Create a regular enemy for a given stage - using CIFI formulas
     pub fn new(index: i32, stage: i32, hunter_type: HunterType) -> Self {
-        let (hp, power, regen, special_chance, special_damage, dr, evade_chance, effect_chance, speed) = 
+        Self::new_with_elements(index, stage, hunter_type, Vec::new(), Vec::new(), None, None)
+    }
+
+    /// Create a regular enemy with explicit elemental weaknesses/immunities. `forced_element`
+    /// overrides the stage-cycled `defense_element` when set, e.g. from
+    /// `BuildConfig::forced_enemy_element` or an active `trample`/`decay` mod.
+    /// `effectiveness_table` overrides the built-in `ATTR_FIX_TABLE`, e.g. from
+    /// `BuildConfig::effectiveness_table`.
+    pub fn new_with_elements(
+        index: i32,
+        stage: i32,
+        hunter_type: HunterType,
+        weaknesses: Vec<String>,
+        immunities: Vec<String>,
+        forced_element: Option<Element>,
+        effectiveness_table: Option<Vec<Vec<f64>>>,
+    ) -> Self {
+        let (hp, power, regen, special_chance, special_damage, dr, evade_chance, effect_chance, speed) =
             Self::calculate_stats_cifi(stage, hunter_type, false);
-        
+        let (assigned_element, element_level) = assign_element(stage, hunter_type, false);
+        let defense_element = forced_element.unwrap_or(assigned_element);
+        let life_drain = base_life_drain(stage, false);
+        let (race, size) = assign_race_size(stage, hunter_type, false);
+
         Self {
             name: format!("E{:>3}{:>3}", stage, index),
             hp,
@@ -101,27 +467,58 @@ impl Enemy {
             speed,
             base_speed: speed,
             is_boss: false,
-            is_stunned: false,
-            stun_end_time: 0.0,
             enrage_stacks: 0,
             has_secondary: false,
             speed2: 0.0,
             base_speed2: 0.0,
+            weaknesses,
+            immunities,
+            defense_element,
+            element_level,
+            effectiveness_table,
+            statuses: Vec::new(),
+            slow_factor: 1.0,
+            weaken_factor: 1.0,
+            life_drain,
+            reflect_chance: 0.0,
+            reflect_fraction: 0.0,
+            race,
+            size,
+            dots: Vec::new(),
         }
     }
-    
+
     /// Create a boss for a given stage - using CIFI formulas
     pub fn new_boss(stage: i32, hunter_type: HunterType) -> Self {
-        let (hp, power, regen, special_chance, special_damage, dr, evade_chance, effect_chance, speed) = 
+        Self::new_boss_with_elements(stage, hunter_type, Vec::new(), Vec::new(), None, None)
+    }
+
+    /// Create a boss with explicit elemental weaknesses/immunities. `forced_element` overrides
+    /// the stage-cycled `defense_element` when set, e.g. from `BuildConfig::forced_enemy_element`
+    /// or an active `trample`/`decay` mod. `effectiveness_table` overrides the built-in
+    /// `ATTR_FIX_TABLE`, e.g. from `BuildConfig::effectiveness_table`.
+    pub fn new_boss_with_elements(
+        stage: i32,
+        hunter_type: HunterType,
+        weaknesses: Vec<String>,
+        immunities: Vec<String>,
+        forced_element: Option<Element>,
+        effectiveness_table: Option<Vec<Vec<f64>>>,
+    ) -> Self {
+        let (hp, power, regen, special_chance, special_damage, dr, evade_chance, effect_chance, speed) =
             Self::calculate_stats_cifi(stage, hunter_type, true);
-        
+        let (assigned_element, element_level) = assign_element(stage, hunter_type, true);
+        let defense_element = forced_element.unwrap_or(assigned_element);
+        let life_drain = base_life_drain(stage, true);
+        let (race, size) = assign_race_size(stage, hunter_type, true);
+
         // Calculate speed2 for bosses (secondary attack speed)
         let speed2 = if stage >= 200 {
             speed * 1.8  // Secondary attack is slower
         } else {
             0.0
         };
-        
+
         Self {
             name: format!("B{:>3}", stage),
             hp,
@@ -137,12 +534,36 @@ impl Enemy {
             speed,
             base_speed: speed,
             is_boss: true,
-            is_stunned: false,
-            stun_end_time: 0.0,
             enrage_stacks: 0,
             has_secondary: stage >= 200,
             speed2,
             base_speed2: speed2,
+            weaknesses,
+            immunities,
+            defense_element,
+            element_level,
+            effectiveness_table,
+            statuses: Vec::new(),
+            slow_factor: 1.0,
+            weaken_factor: 1.0,
+            life_drain,
+            reflect_chance: 0.15,
+            reflect_fraction: 0.2,
+            race,
+            size,
+            dots: Vec::new(),
+        }
+    }
+
+    /// Resolve the elemental multiplier for an attack of `attack_type` against this enemy:
+    /// 2x when the type is a weakness, 0x when it's an immunity, 1x otherwise.
+    pub fn elemental_multiplier(&self, attack_type: &str) -> f64 {
+        if self.immunities.iter().any(|t| t == attack_type) {
+            0.0
+        } else if self.weaknesses.iter().any(|t| t == attack_type) {
+            2.0
+        } else {
+            1.0
         }
     }
     
@@ -303,26 +724,182 @@ impl Enemy {
     
     /// Apply damage to the enemy
     pub fn take_damage(&mut self, damage: f64) -> f64 {
-        let actual = damage * (1.0 - self.damage_reduction);
+        self.take_damage_typed(damage, "physical")
+    }
+
+    /// Apply damage of a given elemental `attack_type`, scaling by this enemy's config-driven
+    /// weaknesses/immunities and by the `ATTR_FIX_TABLE` attack/defense affinity before the flat
+    /// damage-reduction multiplier. The affinity can be negative (the hit heals instead of
+    /// harming), so `hp` is clamped to `max_hp` rather than assuming damage is non-negative.
+    pub fn take_damage_typed(&mut self, damage: f64, attack_type: &str) -> f64 {
+        let config_multiplier = self.elemental_multiplier(attack_type);
+        let affinity = resolve_affinity(self.element_level, Element::from_str(attack_type), self.defense_element, self.effectiveness_table.as_ref());
+        let actual = damage * config_multiplier * affinity * (1.0 - self.damage_reduction);
         self.hp -= actual;
+        self.hp = self.hp.min(self.max_hp);
         actual
     }
-    
-    /// Apply regeneration
+
+    /// Like `take_damage_typed`, but also folds in `bonus`'s damage multiplier against this
+    /// enemy's `race`/`size` (e.g. a talent that deals extra damage to Beast-type or Large
+    /// enemies) and rolls this enemy's `reflect_chance`, bouncing `reflect_fraction` of the damage
+    /// that got through `damage_reduction` back at the attacker on success. `elemental_delta`
+    /// isolates just the `ATTR_FIX_TABLE` affinity's contribution, regardless of the race/size
+    /// multiplier, the way `SimResult::elemental_damage` wants it. This is the one damage-dealing
+    /// path every hunter attack (primary hit, echo, multistrike, splash, and Knox's deferred
+    /// salvo) funnels through, so reflect/race-size/elemental bookkeeping all stay correct
+    /// regardless of which attack variant lands the hit. The caller is responsible for applying
+    /// `reflected` to the attacker.
+    pub fn take_damage_with_reflect(&mut self, damage: f64, attack_type: &str, bonus: &RaceSizeBonus, rng: &mut impl Rng) -> DamageResult {
+        let config_multiplier = self.elemental_multiplier(attack_type);
+        let affinity = resolve_affinity(self.element_level, Element::from_str(attack_type), self.defense_element, self.effectiveness_table.as_ref());
+        let race_size_multiplier = bonus.resolve(self.race, self.size);
+        let actual = damage * config_multiplier * affinity * race_size_multiplier * (1.0 - self.damage_reduction);
+        self.hp -= actual;
+        self.hp = self.hp.min(self.max_hp);
+
+        let neutral = damage * config_multiplier * race_size_multiplier * (1.0 - self.damage_reduction);
+        let elemental_delta = actual - neutral;
+
+        let reflected = if self.reflect_chance > 0.0 && rng.gen::<f64>() < self.reflect_chance {
+            actual.max(0.0) * self.reflect_fraction
+        } else {
+            0.0
+        };
+
+        DamageResult { applied: actual, elemental_delta, reflected }
+    }
+
+
+    /// Apply a DoT of `source`, or refresh/stack it if one is already active. Returns the slot's
+    /// index in `self.dots` and whether the caller needs to schedule a first `Action::DotTick`:
+    /// true for a brand-new slot, or for an exhausted slot that this call refreshed back to life
+    /// (a slot that still has ticks left already has one in flight).
+    pub fn apply_dot(
+        &mut self,
+        source: DotKind,
+        damage_per_tick: f64,
+        ticks: u32,
+        tick_interval: f64,
+        refreshable: bool,
+        stacking: bool,
+    ) -> (usize, bool) {
+        if let Some((i, existing)) = self.dots.iter_mut().enumerate().find(|(_, d)| d.source == source) {
+            let was_exhausted = existing.ticks_remaining == 0;
+            if stacking {
+                existing.damage_per_tick += damage_per_tick;
+            }
+            if existing.refreshable {
+                existing.ticks_remaining = ticks;
+            }
+            existing.tick_interval = tick_interval;
+            existing.refreshable = refreshable;
+            return (i, was_exhausted && existing.ticks_remaining > 0);
+        }
+
+        self.dots.push(ActiveDot { damage_per_tick, ticks_remaining: ticks, tick_interval, refreshable, source });
+        (self.dots.len() - 1, true)
+    }
+
+    /// Apply one tick of the DoT at `dot_index` through the normal `take_damage` path and
+    /// decrement its remaining ticks. Returns `None` (and leaves the slot alone) once it's
+    /// already exhausted, so a stray event from before a refresh can't double-tick or resurrect
+    /// a finished dot. The caller checks `self.dots[dot_index].ticks_remaining` afterward to
+    /// decide whether to reschedule.
+    pub fn tick_dot(&mut self, dot_index: usize) -> Option<f64> {
+        let dot = self.dots.get_mut(dot_index)?;
+        if dot.ticks_remaining == 0 {
+            return None;
+        }
+        let damage = dot.damage_per_tick;
+        dot.ticks_remaining -= 1;
+        self.take_damage(damage);
+        Some(damage)
+    }
+
+    /// Apply regeneration. Enemies actively poisoned do not regen, mirroring the "no regen
+    /// while poisoned" invariant of most ailment systems.
     pub fn regen_hp(&mut self) {
-        if self.hp < self.max_hp && self.hp > 0.0 {
+        let poisoned = self.statuses.iter().any(|s| s.kind == StatusKind::Poison);
+        if self.hp < self.max_hp && self.hp > 0.0 && !poisoned {
             self.hp = (self.hp + self.regen).min(self.max_hp);
         }
     }
-    
+
+    /// Try to inflict a status ailment, rolling a resist check against `effect_chance` first
+    /// (enemies only gain `effect_chance` at stage 300+, so earlier enemies never resist).
+    /// Returns `true` if the status was applied, `false` if it was resisted.
+    pub fn apply_status(&mut self, kind: StatusKind, magnitude: f64, duration: f64, now: f64, tick_interval: f64, rng: &mut impl Rng) -> bool {
+        if rng.gen::<f64>() < self.effect_chance {
+            return false;
+        }
+
+        if let Some(existing) = self.statuses.iter_mut().find(|s| s.kind == kind) {
+            // Re-applying refreshes duration rather than stacking independent entries.
+            existing.end_time = now + duration;
+            existing.magnitude = magnitude;
+            existing.next_tick = now + tick_interval;
+        } else {
+            self.statuses.push(StatusEffect {
+                kind,
+                end_time: now + duration,
+                magnitude,
+                next_tick: now + tick_interval,
+                tick_interval,
+                stacks: 1,
+                max_stacks: 1,
+            });
+        }
+
+        self.recalculate_status_factors();
+        true
+    }
+
+    /// Advance all active statuses to `now`: applies periodic Poison/Burn damage, drops expired
+    /// effects, and recomputes the Slow/Weaken multipliers from whatever remains active.
+    pub fn tick_statuses(&mut self, now: f64) {
+        for status in &mut self.statuses {
+            if matches!(status.kind, StatusKind::Poison | StatusKind::Burn) {
+                while status.next_tick <= now && status.end_time > status.next_tick {
+                    self.hp = (self.hp - status.magnitude).max(0.0);
+                    status.next_tick += status.tick_interval;
+                }
+            }
+        }
+
+        self.statuses.retain(|s| s.end_time > now);
+        self.recalculate_status_factors();
+    }
+
+    /// Whether a status of this kind is currently active.
+    pub fn has_status(&self, kind: StatusKind) -> bool {
+        self.statuses.iter().any(|s| s.kind == kind)
+    }
+
+    /// Recompute `slow_factor`/`weaken_factor` from the strongest currently-active Slow/Weaken
+    /// effect (magnitude is the fraction reduced, e.g. 0.3 slow = 70% speed).
+    fn recalculate_status_factors(&mut self) {
+        let slow_magnitude = self.statuses.iter()
+            .filter(|s| s.kind == StatusKind::Slow)
+            .map(|s| s.magnitude)
+            .fold(0.0_f64, f64::max);
+        self.slow_factor = (1.0 - slow_magnitude).max(0.0);
+
+        let weaken_magnitude = self.statuses.iter()
+            .filter(|s| s.kind == StatusKind::Weaken)
+            .map(|s| s.magnitude)
+            .fold(0.0_f64, f64::max);
+        self.weaken_factor = (1.0 - weaken_magnitude).max(0.0);
+    }
+
     /// Get attack damage with possible crit - CIFI enrage mechanics
     pub fn get_attack_damage(&self, rng: &mut impl Rng) -> (f64, bool) {
         // At 200+ enrage stacks, damage is tripled and always crits
-        let power = if self.enrage_stacks > 200 {
+        let power = (if self.enrage_stacks > 200 {
             self.base_power * 3.0
         } else {
             self.base_power
-        };
+        }) * self.weaken_factor;
         
         let crit_chance = if self.enrage_stacks > 200 {
             1.0  // Always crit at max enrage
@@ -336,7 +913,21 @@ impl Enemy {
             (power, false)
         }
     }
-    
+
+    /// Heal the enemy by `amount`, clamped to `max_hp`.
+    pub fn heal(&mut self, amount: f64) {
+        self.hp = (self.hp + amount).min(self.max_hp);
+    }
+
+    /// Like `get_attack_damage`, but also resolves the lifesteal heal this attack generates.
+    /// Drain scales with enrage stacks so bosses punish slow kills progressively harder.
+    pub fn resolve_attack(&self, rng: &mut impl Rng) -> (f64, bool, f64) {
+        let (damage, is_crit) = self.get_attack_damage(rng);
+        let drain_scale = 1.0 + self.enrage_stacks as f64 / 100.0;
+        let heal_amount = damage * self.life_drain * drain_scale;
+        (damage, is_crit, heal_amount)
+    }
+
     /// Add enrage stack (boss only) - CIFI mechanics
     /// Enrage reduces attack speed, doesn't increase power until 200 stacks
     pub fn add_enrage(&mut self) {
@@ -353,13 +944,13 @@ impl Enemy {
         }
     }
     
-    /// Get current attack speed (accounting for enrage)
+    /// Get current attack speed (accounting for enrage and active Slow effects)
     pub fn get_speed(&self) -> f64 {
-        self.speed
+        self.speed * self.slow_factor
     }
-    
-    /// Get current secondary attack speed (accounting for enrage)
+
+    /// Get current secondary attack speed (accounting for enrage and active Slow effects)
     pub fn get_speed2(&self) -> f64 {
-        self.speed2
+        self.speed2 * self.slow_factor
     }
 }
@@ -0,0 +1,119 @@
+//! Deterministic fixed-point numeric type for platform-independent stage/loot math.
+//!
+//! Plain `f64` arithmetic can diverge subtly across platforms (x87 vs. SSE rounding, fma
+//! contraction, differing libm `sqrt`/transcendental implementations), which makes Monte Carlo
+//! runs that lean on it not bit-reproducible machine to machine. `Fixed` sidesteps that for the
+//! handful of calculations that need to compare exactly across machines - currently
+//! `Hunter::calculate_loot`'s stage/loot-multiplier chain - by doing the arithmetic as `i64`
+//! integers instead, which every platform executes identically.
+
+use std::cmp::Ordering;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Number of fractional bits in the Q32.32 representation.
+const FRAC_BITS: u32 = 32;
+
+/// A Q32.32 fixed-point number: a plain `i64` whose low 32 bits are the fractional part. Safe to
+/// `Copy`/compare/hash like any integer, and converts to/from `f64` at the boundary with the
+/// rest of the (still `f64`-based) simulation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fixed(i64);
+
+impl Fixed {
+    pub const ZERO: Fixed = Fixed(0);
+    pub const ONE: Fixed = Fixed(1 << FRAC_BITS);
+
+    /// Build a `Fixed` from its raw Q32.32 bit pattern.
+    pub const fn from_bits(bits: i64) -> Self {
+        Fixed(bits)
+    }
+
+    /// The raw Q32.32 bit pattern.
+    pub const fn to_bits(self) -> i64 {
+        self.0
+    }
+
+    /// Convert from an `f64`, rounding to the nearest representable Q32.32 value.
+    pub fn from_f64(value: f64) -> Self {
+        Fixed((value * (1i64 << FRAC_BITS) as f64).round() as i64)
+    }
+
+    /// Convert back to `f64` for interop with the rest of the (f64-based) simulation.
+    pub fn to_f64(self) -> f64 {
+        self.0 as f64 / (1i64 << FRAC_BITS) as f64
+    }
+
+    /// Integer square root via Newton-Raphson on the Q32.32 value, with a fixed iteration count
+    /// so the result is identical on every platform regardless of `self`'s magnitude. Negative
+    /// inputs return `ZERO`, matching `f64::sqrt`'s domain restriction without propagating NaN.
+    pub fn sqrt(self) -> Fixed {
+        if self.0 <= 0 {
+            return Fixed::ZERO;
+        }
+        // Work in the doubly-shifted i128 domain (value << FRAC_BITS): the integer square root of
+        // raw * 2^32 is sqrt(raw / 2^32) * 2^32, i.e. already the Q32.32 raw result, with no shift back.
+        let target = (self.0 as i128) << FRAC_BITS;
+        let mut guess = self.0 as i128;
+        if guess == 0 {
+            guess = 1;
+        }
+        const ITERATIONS: u32 = 40;
+        for _ in 0..ITERATIONS {
+            guess = (guess + target / guess) / 2;
+        }
+        Fixed(guess as i64)
+    }
+}
+
+impl Add for Fixed {
+    type Output = Fixed;
+    fn add(self, rhs: Fixed) -> Fixed {
+        Fixed(self.0 + rhs.0)
+    }
+}
+
+impl Sub for Fixed {
+    type Output = Fixed;
+    fn sub(self, rhs: Fixed) -> Fixed {
+        Fixed(self.0 - rhs.0)
+    }
+}
+
+impl Neg for Fixed {
+    type Output = Fixed;
+    fn neg(self) -> Fixed {
+        Fixed(-self.0)
+    }
+}
+
+impl Mul for Fixed {
+    type Output = Fixed;
+    /// Promote both operands to `i128`, multiply, then arithmetic-shift right by `FRAC_BITS` to
+    /// requantize back to Q32.32 - avoids the `i64` overflow a direct multiply would hit.
+    fn mul(self, rhs: Fixed) -> Fixed {
+        let product = (self.0 as i128) * (rhs.0 as i128);
+        Fixed((product >> FRAC_BITS) as i64)
+    }
+}
+
+impl Div for Fixed {
+    type Output = Fixed;
+    /// Shift the numerator left by `FRAC_BITS` (in the wider `i128` domain) before dividing, so
+    /// the quotient comes out already in Q32.32 instead of truncated to an integer.
+    fn div(self, rhs: Fixed) -> Fixed {
+        let numerator = (self.0 as i128) << FRAC_BITS;
+        Fixed((numerator / rhs.0 as i128) as i64)
+    }
+}
+
+impl PartialOrd<f64> for Fixed {
+    fn partial_cmp(&self, other: &f64) -> Option<Ordering> {
+        self.to_f64().partial_cmp(other)
+    }
+}
+
+impl PartialEq<f64> for Fixed {
+    fn eq(&self, other: &f64) -> bool {
+        self.to_f64() == *other
+    }
+}
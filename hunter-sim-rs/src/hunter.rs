@@ -1,34 +1,69 @@
 //! Hunter implementation with stat calculations for all three hunters
 
-use crate::config::{BuildConfig, HunterType};
+use crate::config::{BuildConfig, HunterType, RateConfig};
+use crate::enemy::{RaceSizeBonus, StackingRule, StatusEffect, StatusKind};
+use crate::fixed::Fixed;
 use crate::stats::SimResult;
 
+/// Identifies one entry in `Hunter::stats`. Combat code that needs to read, set, or temporarily
+/// modify a stat goes through `StatId` rather than naming a struct field directly, so adding a
+/// new stat is a one-line enum change instead of touching every `create_*` struct literal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatId {
+    MaxHp,
+    Power,
+    Regen,
+    DamageReduction,
+    EvadeChance,
+    EffectChance,
+    SpecialChance,
+    SpecialDamage,
+    Speed,
+    Lifesteal,
+    BlockChance,
+    ChargeChance,
+    ChargeGained,
+}
+
+impl StatId {
+    /// Number of `StatId` variants; the length of `Hunter::stats`.
+    pub const COUNT: usize = 13;
+}
+
 /// Computed hunter stats ready for combat simulation
 #[derive(Debug, Clone)]
 pub struct Hunter {
     pub hunter_type: HunterType,
     pub level: i32,
-    
-    // Core stats
-    pub max_hp: f64,
+
+    /// Elemental type this hunter's attacks carry (e.g. "fire", "cold", "physical"). Resolved
+    /// against an `Enemy`'s `weaknesses`/`immunities` to scale damage.
+    pub damage_type: String,
+
+    /// Backing store for every `StatId`, indexed by `id as usize`. Read/write through
+    /// `get_stat_value`/`set_stat_value`/`change_stat_value`, or the named accessor methods
+    /// below (`power()`, `max_hp()`, ...) at call sites that just want the current value.
+    pub stats: [f64; StatId::COUNT],
+
+    // Current HP is a runtime pool bounded by the MaxHp stat, not itself a modifiable combat
+    // stat, so it stays a plain field rather than living in `stats`.
     pub hp: f64,
-    pub power: f64,
-    pub regen: f64,
-    pub damage_reduction: f64,
-    pub evade_chance: f64,
-    pub effect_chance: f64,
-    pub special_chance: f64,
-    pub special_damage: f64,
-    pub speed: f64,
-    pub lifesteal: f64,
-    
+
     // Knox-specific
-    pub block_chance: f64,
     pub charge: f64,
-    pub charge_chance: f64,
-    pub charge_gained: f64,
     pub salvo_projectiles: i32,
-    
+    /// Seconds a projectile spends in flight before its damage lands; see
+    /// `simulation::Action::DeferredDamage`.
+    pub projectile_flight_time: f64,
+    /// How many secondary enemies an attack splashes onto besides its primary target, when
+    /// more than one enemy is alive on the current stage.
+    pub splash_count: i32,
+    /// Fraction of an attack's damage each splash target takes.
+    pub splash_fraction: f64,
+    /// This build's bonus damage against specific enemy race/size categories, applied via
+    /// `Enemy::take_damage_with_reflect`. Defaults to no bonus.
+    pub race_size_bonus: RaceSizeBonus,
+
     // Talent values (for combat mechanics)
     pub death_is_my_companion: i32,
     pub life_of_the_hunt: i32,
@@ -50,10 +85,6 @@ pub struct Hunter {
     pub dance_of_dashes: i32,
     pub vectid_elixir: i32,
     
-    // Ozzy runtime state
-    pub trickster_charges: i32,
-    pub empowered_regen: i32,
-    
     // Knox talents
     pub calypsos_advantage: i32,
     pub ghost_bullets: i32,
@@ -81,25 +112,122 @@ pub struct Hunter {
     pub space_pirate_armory: i32,
     pub soul_amplification: i32,
     pub fortification_elixir: i32,
-    pub empowered_block_regen: i32,  // Counter for regen buff after block
-    
+
     // Mod flags
     pub has_trample: bool,
     pub has_decay: bool,
     
     // Loot multiplier
     pub loot_mult: f64,
+    /// Global loot/XP rate knobs and over-leveled farming penalty, snapshotted from
+    /// `BuildConfig::rate_config` at creation time; see `calculate_loot`.
+    pub rate_config: RateConfig,
     
     // Combat tracking
     pub result: SimResult,
     pub current_stage: i32,
     pub revive_count: i32,
     pub max_revives: i32,
-    pub hundred_souls_stacks: i32,  // Knox
-    pub decay_stacks: i32,  // Ozzy crippling shots
+    /// Every active timed buff and stacking counter - the empowered-regen window after Unfair
+    /// Advantage/block, Trickster's Boon charges, Crippling Shots decay stacks, Hundred Souls
+    /// stacks, etc. - read through `apply_effect`/`stack_count`/`spend_one_stack`/
+    /// `consume_stacks`/`tick_effects` instead of bespoke counters per effect. Reuses
+    /// `enemy::StatusEffect` so both sides share the same apply/expire/check vocabulary.
+    pub statuses: Vec<StatusEffect>,
+}
+
+/// Build a `Hunter::stats` array from its computed components. `block_chance`/`charge_chance`/
+/// `charge_gained` default to 0.0 for hunters other than Knox, who pass their computed values.
+fn build_stats(
+    max_hp: f64,
+    power: f64,
+    regen: f64,
+    damage_reduction: f64,
+    evade_chance: f64,
+    effect_chance: f64,
+    special_chance: f64,
+    special_damage: f64,
+    speed: f64,
+    lifesteal: f64,
+    block_chance: f64,
+    charge_chance: f64,
+    charge_gained: f64,
+) -> [f64; StatId::COUNT] {
+    let mut stats = [0.0; StatId::COUNT];
+    stats[StatId::MaxHp as usize] = max_hp;
+    stats[StatId::Power as usize] = power;
+    stats[StatId::Regen as usize] = regen;
+    stats[StatId::DamageReduction as usize] = damage_reduction;
+    stats[StatId::EvadeChance as usize] = evade_chance;
+    stats[StatId::EffectChance as usize] = effect_chance;
+    stats[StatId::SpecialChance as usize] = special_chance;
+    stats[StatId::SpecialDamage as usize] = special_damage;
+    stats[StatId::Speed as usize] = speed.max(0.1);
+    stats[StatId::Lifesteal as usize] = lifesteal;
+    stats[StatId::BlockChance as usize] = block_chance;
+    stats[StatId::ChargeChance as usize] = charge_chance;
+    stats[StatId::ChargeGained as usize] = charge_gained;
+    stats
+}
+
+/// Resolve a hunter's elemental damage type from config, letting attunement attributes override
+/// the configured base type.
+fn resolve_damage_type(c: &BuildConfig) -> String {
+    if c.get_attr("fire_attunement") > 0 {
+        "fire".to_string()
+    } else if c.get_attr("cold_attunement") > 0 {
+        "cold".to_string()
+    } else if c.get_attr("radiation_attunement") > 0 {
+        "radiation".to_string()
+    } else {
+        c.damage_type.clone().unwrap_or_else(|| "physical".to_string())
+    }
 }
 
 impl Hunter {
+    /// Read a stat directly.
+    pub fn get_stat_value(&self, id: StatId) -> f64 {
+        self.stats[id as usize]
+    }
+
+    /// Overwrite a stat directly.
+    pub fn set_stat_value(&mut self, id: StatId, value: f64) {
+        self.stats[id as usize] = value;
+    }
+
+    /// Add `delta` to a stat; a no-op on `delta == 0.0`, like the original named-field writes it
+    /// replaces.
+    pub fn change_stat_value(&mut self, id: StatId, delta: f64) {
+        if delta == 0.0 {
+            return;
+        }
+        self.stats[id as usize] += delta;
+    }
+
+    /// Apply `delta` to `id`, run `f`, then revert - for combat effects that need a stat
+    /// temporarily shifted (e.g. a debuff dropping enemy-inflicted DR for a few ticks) without
+    /// the caller having to remember which concrete field to restore.
+    pub fn with_temp_modifier<T>(&mut self, id: StatId, delta: f64, f: impl FnOnce(&mut Self) -> T) -> T {
+        self.change_stat_value(id, delta);
+        let result = f(self);
+        self.change_stat_value(id, -delta);
+        result
+    }
+
+    pub fn max_hp(&self) -> f64 { self.get_stat_value(StatId::MaxHp) }
+    pub fn power(&self) -> f64 { self.get_stat_value(StatId::Power) }
+    pub fn regen(&self) -> f64 { self.get_stat_value(StatId::Regen) }
+    pub fn damage_reduction(&self) -> f64 { self.get_stat_value(StatId::DamageReduction) }
+    pub fn evade_chance(&self) -> f64 { self.get_stat_value(StatId::EvadeChance) }
+    pub fn effect_chance(&self) -> f64 { self.get_stat_value(StatId::EffectChance) }
+    pub fn special_chance(&self) -> f64 { self.get_stat_value(StatId::SpecialChance) }
+    pub fn special_damage(&self) -> f64 { self.get_stat_value(StatId::SpecialDamage) }
+    pub fn speed(&self) -> f64 { self.get_stat_value(StatId::Speed) }
+    pub fn lifesteal(&self) -> f64 { self.get_stat_value(StatId::Lifesteal) }
+    pub fn block_chance(&self) -> f64 { self.get_stat_value(StatId::BlockChance) }
+    pub fn charge_chance(&self) -> f64 { self.get_stat_value(StatId::ChargeChance) }
+    pub fn charge_gained(&self) -> f64 { self.get_stat_value(StatId::ChargeGained) }
+
     /// Create a hunter from a build configuration
     pub fn from_config(config: &BuildConfig) -> Self {
         match config.get_hunter_type() {
@@ -209,22 +337,18 @@ impl Hunter {
         Self {
             hunter_type: HunterType::Borge,
             level,
-            max_hp,
+            damage_type: resolve_damage_type(c),
+            stats: build_stats(
+                max_hp, power, regen, damage_reduction, evade_chance, effect_chance,
+                special_chance, special_damage, speed, lifesteal, 0.0, 0.0, 0.0,
+            ),
             hp: max_hp,
-            power,
-            regen,
-            damage_reduction,
-            evade_chance,
-            effect_chance,
-            special_chance,
-            special_damage,
-            speed: speed.max(0.1),
-            lifesteal,
-            block_chance: 0.0,
             charge: 0.0,
-            charge_chance: 0.0,
-            charge_gained: 0.0,
             salvo_projectiles: 0,
+            projectile_flight_time: 0.15,
+            splash_count: c.splash_count.unwrap_or(0),
+            splash_fraction: c.splash_fraction.unwrap_or(0.0),
+            race_size_bonus: c.race_size_bonus.unwrap_or_default(),
             death_is_my_companion: dimc,
             life_of_the_hunt: c.get_talent("life_of_the_hunt"),
             unfair_advantage: c.get_talent("unfair_advantage"),
@@ -240,8 +364,6 @@ impl Hunter {
             thousand_needles: 0,
             dance_of_dashes: 0,
             vectid_elixir: 0,
-            trickster_charges: 0,
-            empowered_regen: 0,
             calypsos_advantage: 0,
             ghost_bullets: 0,
             finishing_move: 0,
@@ -260,16 +382,15 @@ impl Hunter {
             space_pirate_armory: 0,
             soul_amplification: 0,
             fortification_elixir: 0,
-            empowered_block_regen: 0,
             has_trample: *c.mods.get("trample").unwrap_or(&false),
             has_decay: false,
             loot_mult,
+            rate_config: c.rate_config.clone().unwrap_or_default(),
             result: SimResult::default(),
             current_stage: 0,
             revive_count: 0,
             max_revives,
-            hundred_souls_stacks: 0,
-            decay_stacks: 0,
+            statuses: Vec::new(),
         }
     }
     
@@ -356,22 +477,18 @@ impl Hunter {
         Self {
             hunter_type: HunterType::Ozzy,
             level,
-            max_hp,
+            damage_type: resolve_damage_type(c),
+            stats: build_stats(
+                max_hp, power, regen, damage_reduction, evade_chance, effect_chance,
+                special_chance, special_damage, speed, lifesteal, 0.0, 0.0, 0.0,
+            ),
             hp: max_hp,
-            power,
-            regen,
-            damage_reduction,
-            evade_chance,
-            effect_chance,
-            special_chance,
-            special_damage,
-            speed: speed.max(0.1),
-            lifesteal,
-            block_chance: 0.0,
             charge: 0.0,
-            charge_chance: 0.0,
-            charge_gained: 0.0,
             salvo_projectiles: 0,
+            projectile_flight_time: 0.15,
+            splash_count: c.splash_count.unwrap_or(0),
+            splash_fraction: c.splash_fraction.unwrap_or(0.0),
+            race_size_bonus: c.race_size_bonus.unwrap_or_default(),
             death_is_my_companion: dimc,
             life_of_the_hunt: c.get_talent("life_of_the_hunt"),
             unfair_advantage: c.get_talent("unfair_advantage"),
@@ -387,8 +504,6 @@ impl Hunter {
             thousand_needles: c.get_talent("thousand_needles"),
             dance_of_dashes: c.get_attr("dance_of_dashes"),
             vectid_elixir: c.get_attr("vectid_elixir"),
-            trickster_charges: 0,
-            empowered_regen: 0,
             calypsos_advantage: 0,
             ghost_bullets: 0,
             finishing_move: 0,
@@ -407,16 +522,15 @@ impl Hunter {
             space_pirate_armory: 0,
             soul_amplification: 0,
             fortification_elixir: 0,
-            empowered_block_regen: 0,
             has_trample: false,
             has_decay: *c.mods.get("decay").unwrap_or(&false),
             loot_mult,
+            rate_config: c.rate_config.clone().unwrap_or_default(),
             result: SimResult::default(),
             current_stage: 0,
             revive_count: 0,
             max_revives,
-            hundred_souls_stacks: 0,
-            decay_stacks: 0,
+            statuses: Vec::new(),
         }
     }
     
@@ -486,22 +600,19 @@ impl Hunter {
         Self {
             hunter_type: HunterType::Knox,
             level,
-            max_hp,
+            damage_type: resolve_damage_type(c),
+            stats: build_stats(
+                max_hp, power, regen, damage_reduction, 0.0 /* Knox uses block instead */,
+                effect_chance, special_chance, special_damage, speed, 0.0, block_chance,
+                charge_chance, charge_gained,
+            ),
             hp: max_hp,
-            power,
-            regen,
-            damage_reduction,
-            evade_chance: 0.0,  // Knox uses block instead
-            effect_chance,
-            special_chance,
-            special_damage,
-            speed: speed.max(0.1),
-            lifesteal: 0.0,
-            block_chance,
             charge: 0.0,
-            charge_chance,
-            charge_gained,
             salvo_projectiles,
+            projectile_flight_time: 0.15,
+            splash_count: c.splash_count.unwrap_or(0),
+            splash_fraction: c.splash_fraction.unwrap_or(0.0),
+            race_size_bonus: c.race_size_bonus.unwrap_or_default(),
             death_is_my_companion: dimc,
             life_of_the_hunt: 0,
             unfair_advantage: c.get_talent("unfair_advantage"),
@@ -517,8 +628,6 @@ impl Hunter {
             thousand_needles: 0,
             dance_of_dashes: 0,
             vectid_elixir: 0,
-            trickster_charges: 0,
-            empowered_regen: 0,
             calypsos_advantage: c.get_talent("calypsos_advantage"),
             ghost_bullets: c.get_talent("ghost_bullets"),
             finishing_move: c.get_talent("finishing_move"),
@@ -537,76 +646,160 @@ impl Hunter {
             space_pirate_armory: c.get_attr("space_pirate_armory"),
             soul_amplification: c.get_attr("soul_amplification"),
             fortification_elixir: c.get_attr("fortification_elixir"),
-            empowered_block_regen: 0,
             has_trample: false,
             has_decay: false,
             loot_mult,
+            rate_config: c.rate_config.clone().unwrap_or_default(),
             result: SimResult::default(),
             current_stage: 0,
             revive_count: 0,
             max_revives,
-            hundred_souls_stacks: 0,
-            decay_stacks: 0,
+            statuses: Vec::new(),
         }
     }
     
     /// Reset hunter for a new simulation
     pub fn reset(&mut self) {
-        self.hp = self.max_hp;
+        self.hp = self.max_hp();
         self.current_stage = 0;
         self.revive_count = 0;
         self.charge = 0.0;
-        self.hundred_souls_stacks = 0;
-        self.trickster_charges = 0;
-        self.empowered_regen = 0;
-        self.empowered_block_regen = 0;
-        self.decay_stacks = 0;
+        self.statuses.clear();
         self.result = SimResult::default();
     }
-    
+
     /// Check if hunter is dead
     pub fn is_dead(&self) -> bool {
         self.hp <= 0.0
     }
-    
-    /// Apply regeneration
-    pub fn regen_hp(&mut self) {
-        if self.hp < self.max_hp {
-            // Vectid Elixir - empowered regen for 5 ticks after Unfair Advantage
-            let mut regen_value = if self.empowered_regen > 0 {
-                self.empowered_regen -= 1;
-                self.regen * (1.0 + self.vectid_elixir as f64 * 0.15)
-            } else {
-                self.regen
-            };
-            
-            // Fortification Elixir (Knox) - +10% regen for 5 ticks after block
-            if self.empowered_block_regen > 0 {
-                self.empowered_block_regen -= 1;
-                regen_value *= 1.0 + self.fortification_elixir as f64 * 0.10;
+
+    /// Whether a status of this kind is currently active.
+    pub fn has_status(&self, kind: StatusKind) -> bool {
+        self.statuses.iter().any(|s| s.kind == kind)
+    }
+
+    /// Current stack count for a stacking effect (e.g. `DecayStacks`/`TricksterCharges`), or 0
+    /// if it isn't active.
+    pub fn stack_count(&self, kind: StatusKind) -> i32 {
+        self.statuses.iter().find(|s| s.kind == kind).map(|s| s.stacks).unwrap_or(0)
+    }
+
+    /// Apply (or refresh/stack) a timed buff. Unlike `Enemy::apply_status`, hunter buffs are
+    /// self-inflicted and always succeed - there's no resist roll. `rule` controls whether
+    /// reapplying just refreshes the duration or also adds a stack (capped at `max_stacks`).
+    pub fn apply_effect(&mut self, kind: StatusKind, now: f64, duration: f64, magnitude: f64, max_stacks: i32, rule: StackingRule) {
+        if let Some(existing) = self.statuses.iter_mut().find(|s| s.kind == kind) {
+            existing.end_time = now + duration;
+            existing.magnitude = magnitude;
+            if rule == StackingRule::AddStack {
+                existing.stacks = (existing.stacks + 1).min(max_stacks);
             }
-            
+        } else {
+            self.statuses.push(StatusEffect {
+                kind,
+                end_time: now + duration,
+                magnitude,
+                next_tick: now,
+                tick_interval: 0.0,
+                stacks: 1,
+                max_stacks,
+            });
+        }
+    }
+
+    /// Add `count` stacks at once (e.g. Crippling Shots adding a whole level's worth of decay
+    /// stacks per proc), capped at `max_stacks`, creating the effect if it isn't already active.
+    pub fn add_stacks(&mut self, kind: StatusKind, now: f64, duration: f64, max_stacks: i32, count: i32) {
+        if let Some(existing) = self.statuses.iter_mut().find(|s| s.kind == kind) {
+            existing.end_time = now + duration;
+            existing.stacks = (existing.stacks + count).min(max_stacks);
+        } else {
+            self.statuses.push(StatusEffect {
+                kind,
+                end_time: now + duration,
+                magnitude: 0.0,
+                next_tick: now,
+                tick_interval: 0.0,
+                stacks: count.min(max_stacks),
+                max_stacks,
+            });
+        }
+    }
+
+    /// Apply (or refresh) a plain timed buff with no stacking - a thin convenience wrapper
+    /// around `apply_effect` for the common `EmpoweredRegen`/`EmpoweredBlockRegen` case.
+    pub fn apply_timed_status(&mut self, kind: StatusKind, now: f64, duration: f64) {
+        self.apply_effect(kind, now, duration, 0.0, 1, StackingRule::RefreshDuration);
+    }
+
+    /// Remove one stack from a stacking effect, dropping it entirely once it reaches zero.
+    /// Returns `true` if a stack was actually spent.
+    pub fn spend_one_stack(&mut self, kind: StatusKind) -> bool {
+        let Some(effect) = self.statuses.iter_mut().find(|s| s.kind == kind) else { return false };
+        if effect.stacks <= 0 {
+            return false;
+        }
+        effect.stacks -= 1;
+        if effect.stacks <= 0 {
+            self.statuses.retain(|s| s.kind != kind);
+        }
+        true
+    }
+
+    /// Remove all stacks of an effect at once and report how many there were, for effects
+    /// (like Crippling Shots decay) that cash in their whole stack on the next hit.
+    pub fn consume_stacks(&mut self, kind: StatusKind) -> i32 {
+        let stacks = self.stack_count(kind);
+        self.statuses.retain(|s| s.kind != kind);
+        stacks
+    }
+
+    /// Drop any effects that have expired by `now`.
+    pub fn tick_effects(&mut self, now: f64) {
+        self.statuses.retain(|s| s.end_time > now);
+    }
+
+    /// Combined regen multiplier from currently active effects (Vectid Elixir's empowered regen,
+    /// Fortification Elixir's post-block regen), so `regen_hp` reads from one source instead of
+    /// checking each buff by hand.
+    pub fn regen_multiplier(&self) -> f64 {
+        let mut mult = 1.0;
+        if self.has_status(StatusKind::EmpoweredRegen) {
+            mult *= 1.0 + self.vectid_elixir as f64 * 0.15;
+        }
+        if self.has_status(StatusKind::EmpoweredBlockRegen) {
+            mult *= 1.0 + self.fortification_elixir as f64 * 0.10;
+        }
+        mult
+    }
+
+    /// Apply regeneration. Relies on `tick_effects` having already pruned expired buffs for the
+    /// current event time.
+    pub fn regen_hp(&mut self) {
+        if self.hp < self.max_hp() {
+            let regen_value = self.regen() * self.regen_multiplier();
+
             // Lifedrain Inhalers (Borge) - +0.08% missing HP regen per level
-            let missing_hp = self.max_hp - self.hp;
+            let missing_hp = self.max_hp() - self.hp;
             let lifedrain_bonus = if self.lifedrain_inhalers > 0 {
                 missing_hp * 0.0008 * self.lifedrain_inhalers as f64
             } else {
                 0.0
             };
-            
+
             let total_regen = regen_value + lifedrain_bonus;
-            let healed = total_regen.min(self.max_hp - self.hp);
+            let healed = total_regen.min(self.max_hp() - self.hp);
             self.hp += healed;
             self.result.regenerated_hp += healed;
         }
     }
-    
+
     /// Try to revive if possible
     pub fn try_revive(&mut self) -> bool {
         if self.revive_count < self.max_revives {
             self.revive_count += 1;
             // Revive formula: 10% + 5% per level of talent
-            let revive_hp = self.max_hp * (0.10 + 0.05 * self.death_is_my_companion as f64);
+            let revive_hp = self.max_hp() * (0.10 + 0.05 * self.death_is_my_companion as f64);
             self.hp = revive_hp;
             true
         } else {
@@ -614,10 +807,30 @@ impl Hunter {
         }
     }
     
-    /// Calculate loot for the current stage
-    pub fn calculate_loot(&self) -> f64 {
-        // Base loot scales with stage
-        let base_loot = 1.0 + self.current_stage as f64 * 0.1;
-        base_loot * self.loot_mult
+    /// Calculate this stage's loot (split common/uncommon/rare) and XP. The signed gap
+    /// `level - current_stage` is looked up in `rate_config.level_penalty`'s curve and the result
+    /// scales both loot and XP, which is what lets a stage sweep compare farming efficiency of
+    /// over-leveled vs. on-level builds; with the default empty curve the penalty is 1.0 at every
+    /// gap. Returns `(common, uncommon, rare, xp)`.
+    pub fn calculate_loot(&self) -> (f64, f64, f64, f64) {
+        // Routed through `Fixed` (Q32.32) rather than plain `f64` so this chain - the one place
+        // Monte Carlo comparisons most often get run stage-by-stage across machines - comes out
+        // bit-identical regardless of platform rounding behavior.
+        let stage = Fixed::from_f64(self.current_stage as f64);
+        let base_loot = Fixed::ONE + stage * Fixed::from_f64(0.1);
+        let base_xp = Fixed::ONE + stage * Fixed::from_f64(0.05);
+        let gap = self.level - self.current_stage;
+        let penalty = Fixed::from_f64(self.rate_config.level_penalty(gap));
+
+        let loot = base_loot * Fixed::from_f64(self.loot_mult) * Fixed::from_f64(self.rate_config.loot_rate) * penalty;
+        let xp = base_xp * Fixed::from_f64(self.rate_config.xp_rate) * penalty;
+
+        // Split into material tiers the way loot tables typically break down: common drops make
+        // up the bulk, with uncommon and rare increasingly scarce.
+        let common = loot * Fixed::from_f64(0.7);
+        let uncommon = loot * Fixed::from_f64(0.25);
+        let rare = loot * Fixed::from_f64(0.05);
+
+        (common.to_f64(), uncommon.to_f64(), rare.to_f64(), xp.to_f64())
     }
 }
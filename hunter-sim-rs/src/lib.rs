@@ -8,6 +8,11 @@ pub mod enemy;
 pub mod simulation;
 pub mod stats;
 pub mod build_generator;
+pub mod fixed;
+pub mod prng;
+pub mod stage_profile;
+pub mod stats_accumulator;
+pub mod comparison;
 
 #[cfg(feature = "python")]
 mod python;
@@ -18,3 +23,8 @@ pub use enemy::*;
 pub use simulation::*;
 pub use stats::*;
 pub use build_generator::*;
+pub use fixed::*;
+pub use prng::*;
+pub use stage_profile::*;
+pub use stats_accumulator::*;
+pub use comparison::*;
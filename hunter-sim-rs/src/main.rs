@@ -3,9 +3,9 @@
 use clap::{Parser, ValueEnum};
 use hunter_sim_lib::{
     config::BuildConfig,
-    simulation::run_and_aggregate,
+    simulation::{run_and_aggregate, run_and_aggregate_profiled, run_and_aggregate_seeded},
 };
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::time::Instant;
 
 #[derive(Debug, Clone, ValueEnum)]
@@ -23,6 +23,15 @@ struct Args {
     #[arg(short, long)]
     config: PathBuf,
 
+    /// RNG seed for reproducible simulations; identical seeds yield byte-identical stats
+    #[arg(long)]
+    seed: Option<u64>,
+
+    /// Compare every build config in this directory and print a markdown results table
+    /// instead of running a single build
+    #[arg(long)]
+    results_table: Option<PathBuf>,
+
     /// Number of simulations to run
     #[arg(short, long, default_value = "100")]
     num_sims: usize,
@@ -38,11 +47,21 @@ struct Args {
     /// Show timing information
     #[arg(short, long, default_value = "false")]
     timing: bool,
+
+    /// Dump a per-stage loot histogram (count/mean/variance/min/max) to this path once the run
+    /// finishes. Format is inferred from the extension: `.json` for JSON, anything else for CSV.
+    #[arg(long)]
+    stage_profile: Option<PathBuf>,
 }
 
 fn main() {
     let args = Args::parse();
 
+    if let Some(dir) = &args.results_table {
+        print_results_table(dir, args.num_sims, args.seed.unwrap_or(42));
+        return;
+    }
+
     // Load config
     let config = match BuildConfig::from_file(&args.config) {
         Ok(c) => c,
@@ -52,9 +71,27 @@ fn main() {
         }
     };
 
-    // Run simulations
+    // Run simulations. Stage profiling needs a seed to hang the batch off of, so fall back to the
+    // same default seed `--results-table` uses when the user didn't pass `--seed`.
     let start = Instant::now();
-    let stats = run_and_aggregate(&config, args.num_sims, args.parallel);
+    let stats = if let Some(profile_path) = &args.stage_profile {
+        let seed = args.seed.unwrap_or(42);
+        let (stats, profile) = run_and_aggregate_profiled(&config, args.num_sims, seed, args.parallel);
+        let dump = if profile_path.extension().and_then(|e| e.to_str()) == Some("json") {
+            serde_json::to_string_pretty(&profile.to_json()).unwrap()
+        } else {
+            profile.to_csv()
+        };
+        if let Err(e) = std::fs::write(profile_path, dump) {
+            eprintln!("Error writing stage profile to {}: {}", profile_path.display(), e);
+        }
+        stats
+    } else {
+        match args.seed {
+            Some(seed) => run_and_aggregate_seeded(&config, args.num_sims, seed, args.parallel),
+            None => run_and_aggregate(&config, args.num_sims, args.parallel),
+        }
+    };
     let elapsed = start.elapsed();
 
     // Output results
@@ -125,3 +162,61 @@ fn main() {
         }
     }
 }
+
+/// Simulate every build config found in `dir` with the same `seed`/`num_sims` and print a
+/// GitHub-flavored markdown comparison table sorted by average stage, so build tweaks and
+/// regressions are directly diffable across runs.
+fn print_results_table(dir: &Path, num_sims: usize, seed: u64) {
+    let mut configs: Vec<(String, BuildConfig)> = Vec::new();
+
+    let entries = match std::fs::read_dir(dir) {
+        Ok(e) => e,
+        Err(e) => {
+            eprintln!("Error reading results-table directory {}: {}", dir.display(), e);
+            std::process::exit(1);
+        }
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let is_build_file = matches!(
+            path.extension().and_then(|e| e.to_str()),
+            Some("yaml") | Some("yml") | Some("json") | Some("toml")
+        );
+        if !is_build_file {
+            continue;
+        }
+
+        match BuildConfig::from_file(&path) {
+            Ok(config) => {
+                let name = path.file_stem().and_then(|s| s.to_str()).unwrap_or("?").to_string();
+                configs.push((name, config));
+            }
+            Err(e) => eprintln!("Skipping {}: {}", path.display(), e),
+        }
+    }
+
+    let mut rows: Vec<(String, hunter_sim_lib::stats::AggregatedStats)> = configs
+        .into_iter()
+        .map(|(name, config)| (name, run_and_aggregate_seeded(&config, num_sims, seed, false)))
+        .collect();
+
+    rows.sort_by(|a, b| b.1.avg_stage.partial_cmp(&a.1.avg_stage).unwrap_or(std::cmp::Ordering::Equal));
+
+    println!("| Build | Avg Stage ± Std | Survival | Boss1 | Boss2 | Boss3 | Boss4 | Boss5 |");
+    println!("|---|---|---|---|---|---|---|---|");
+    for (name, stats) in &rows {
+        println!(
+            "| {} | {:.1} ± {:.1} | {:.1}% | {:.1}% | {:.1}% | {:.1}% | {:.1}% | {:.1}% |",
+            name,
+            stats.avg_stage,
+            stats.std_stage,
+            stats.survival_rate * 100.0,
+            stats.boss1_survival * 100.0,
+            stats.boss2_survival * 100.0,
+            stats.boss3_survival * 100.0,
+            stats.boss4_survival * 100.0,
+            stats.boss5_survival * 100.0,
+        );
+    }
+}
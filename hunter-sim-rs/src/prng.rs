@@ -0,0 +1,160 @@
+//! Counter-based PRNG for exactly-reproducible, lock-free parallel Monte Carlo runs.
+//!
+//! `run_simulations_parallel_seeded` (see `simulation.rs`) already gets reproducibility by handing
+//! each worker its own `SmallRng` seeded from `seed ^ splitmix64(index)`. `CounterRng` is a second,
+//! coexisting way to get there: instead of deriving one seed per run up front, a single key forks
+//! into independent substreams on demand via `fork(stream_id)`, which is convenient when the
+//! number of streams isn't known until the thread pool actually spins up workers. Each stream is
+//! just a keyed hash of an incrementing counter, so two `CounterRng`s with different keys (or the
+//! same key after forking on different `stream_id`s) can never collide without any shared state or
+//! locking between them.
+
+use rand::{Error, RngCore};
+
+use crate::fixed::Fixed;
+use crate::simulation::splitmix64;
+
+/// SipHash round constants (Aumasson & Bernstein's "somepseudorandomlygeneratedbytes").
+const SIP_V0: u64 = 0x736f6d6570736575;
+const SIP_V1: u64 = 0x646f72616e646f6d;
+const SIP_V2: u64 = 0x6c7967656e657261;
+const SIP_V3: u64 = 0x7465646279746573;
+
+/// One SipHash mixing round.
+fn sip_round(v0: &mut u64, v1: &mut u64, v2: &mut u64, v3: &mut u64) {
+    *v0 = v0.wrapping_add(*v1);
+    *v1 = v1.rotate_left(13);
+    *v1 ^= *v0;
+    *v0 = v0.rotate_left(32);
+
+    *v2 = v2.wrapping_add(*v3);
+    *v3 = v3.rotate_left(16);
+    *v3 ^= *v2;
+
+    *v0 = v0.wrapping_add(*v3);
+    *v3 = v3.rotate_left(21);
+    *v3 ^= *v0;
+
+    *v2 = v2.wrapping_add(*v1);
+    *v1 = v1.rotate_left(17);
+    *v1 ^= *v2;
+    *v2 = v2.rotate_left(32);
+}
+
+/// SipHash-1-3 (1 compression round, 3 finalization rounds) of a single 8-byte message - exactly
+/// the counter value - under a 128-bit key. One compression round is weaker than the standard
+/// SipHash-2-4 but is plenty for statistical decorrelation between counter values in a simulation
+/// RNG, and it's cheaper per draw, which matters since this runs once per loot roll / stage
+/// advance.
+fn siphash13(k0: u64, k1: u64, counter: u64) -> u64 {
+    let mut v0 = k0 ^ SIP_V0;
+    let mut v1 = k1 ^ SIP_V1;
+    let mut v2 = k0 ^ SIP_V2;
+    let mut v3 = k1 ^ SIP_V3;
+
+    // The only data block: the counter itself.
+    v3 ^= counter;
+    sip_round(&mut v0, &mut v1, &mut v2, &mut v3);
+    v0 ^= counter;
+
+    // Final block folds in the message length (always 8 bytes, no trailing partial block).
+    let last_block = 8u64 << 56;
+    v3 ^= last_block;
+    sip_round(&mut v0, &mut v1, &mut v2, &mut v3);
+    v0 ^= last_block;
+
+    v2 ^= 0xff;
+    sip_round(&mut v0, &mut v1, &mut v2, &mut v3);
+    sip_round(&mut v0, &mut v1, &mut v2, &mut v3);
+    sip_round(&mut v0, &mut v1, &mut v2, &mut v3);
+
+    v0 ^ v1 ^ v2 ^ v3
+}
+
+/// A counter-based PRNG: `next_raw()` is `siphash13(key, counter++)`. Unlike a mutable-state
+/// generator (e.g. `SmallRng`'s xoshiro state), any `(key, counter)` pair can be recomputed
+/// independently of every draw before it, which is what makes `fork` safe to call from multiple
+/// threads without coordinating with the stream it forked from.
+#[derive(Debug, Clone, Copy)]
+pub struct CounterRng {
+    key: [u64; 2],
+    counter: u64,
+}
+
+impl CounterRng {
+    /// Expand a single `u64` seed into a 128-bit key via two independent SplitMix64 draws, the
+    /// same finalizer `simulation::seed_for_index` uses to spread one master seed across indices.
+    pub fn from_seed(seed: u64) -> Self {
+        Self::from_key(splitmix64(seed), splitmix64(seed ^ 0x9E3779B97F4A7C15))
+    }
+
+    /// Build directly from an explicit 128-bit key, counter starting at 0.
+    pub fn from_key(k0: u64, k1: u64) -> Self {
+        Self { key: [k0, k1], counter: 0 }
+    }
+
+    /// Derive an independent substream by XOR-ing `stream_id` into one half of the key and
+    /// resetting the counter. Because the two halves of the key are never combined except inside
+    /// the hash, streams forked with different `stream_id`s are disjoint: no counter value from
+    /// one can ever coincide with a counter value from another under the same key half.
+    pub fn fork(&self, stream_id: u64) -> Self {
+        Self {
+            key: [self.key[0] ^ stream_id, self.key[1]],
+            counter: 0,
+        }
+    }
+
+    fn next_raw(&mut self) -> u64 {
+        let out = siphash13(self.key[0], self.key[1], self.counter);
+        self.counter = self.counter.wrapping_add(1);
+        out
+    }
+
+    /// Uniform integer in `[low, high)`. Uses plain modulo reduction, which has a
+    /// slight bias toward the low end when `high - low` doesn't evenly divide 2^64, which is
+    /// negligible for the loot-roll/stage-advance ranges this is used for.
+    pub fn gen_range(&mut self, low: u64, high: u64) -> u64 {
+        assert!(high > low, "gen_range: high must be greater than low");
+        low + self.next_raw() % (high - low)
+    }
+
+    /// Uniform `f64` in `[0, 1)`, using the top 53 bits of the hash (the full mantissa width of an
+    /// `f64`).
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_raw() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+
+    /// Uniform `Fixed` in `[0, 1)`, for callers that want a fully deterministic (no `f64`
+    /// rounding) draw end to end.
+    pub fn next_fixed(&mut self) -> Fixed {
+        // The top 32 bits of the hash are the fractional bits of a Q32.32 value in [0, 1).
+        Fixed::from_bits((self.next_raw() >> 32) as i64)
+    }
+}
+
+impl RngCore for CounterRng {
+    fn next_u32(&mut self) -> u32 {
+        self.next_raw() as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.next_raw()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        let mut chunks = dest.chunks_exact_mut(8);
+        for chunk in &mut chunks {
+            chunk.copy_from_slice(&self.next_raw().to_le_bytes());
+        }
+        let remainder = chunks.into_remainder();
+        if !remainder.is_empty() {
+            let bytes = self.next_raw().to_le_bytes();
+            remainder.copy_from_slice(&bytes[..remainder.len()]);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
@@ -2,43 +2,133 @@
 
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
+use pyo3::create_exception;
+use pyo3::exceptions::PyException;
 use crate::config::{BuildConfig, HunterType, Meta};
-use crate::simulation::run_and_aggregate;
+use crate::simulation::{run_simulations_parallel, run_simulations_sequential};
+use crate::stats::AggregatedStats;
 use crate::build_generator::{BuildGenerator, AttributeInfo, TalentInfo};
 use std::collections::HashMap;
 
+// Exception hierarchy so Python callers can distinguish a malformed config from a
+// simulation-time failure from a bad build-generation request, instead of catching generic
+// ValueError/RuntimeError for everything.
+create_exception!(hunter_sim_lib, HunterSimError, PyException);
+create_exception!(hunter_sim_lib, ConfigError, HunterSimError);
+create_exception!(hunter_sim_lib, SimulationError, HunterSimError);
+create_exception!(hunter_sim_lib, BuildGenerationError, HunterSimError);
+
+/// Run `num_sims` simulations in fixed batches (1-5% of `num_sims` each), each batch released to
+/// `allow_threads` so the GIL is free while the heavy work runs. Between batches, re-acquires the
+/// GIL (already held via `py` at that point, same as `Python::with_gil` would hand back) to tick
+/// `progress(report_offset + completed, report_total)` if given, and stops early - returning the
+/// partial aggregate over whatever completed, plus `true` - if that callback returns `False`.
+///
+/// `report_offset`/`report_total` let a caller juggling several configs (see `simulate_batch`)
+/// report progress across the whole sweep instead of just this one config's `num_sims`; a
+/// single-config caller passes `0`/`num_sims` so the callback just sees `(completed, num_sims)`.
+fn run_batched_with_progress_impl(
+    py: Python<'_>,
+    config: &BuildConfig,
+    num_sims: usize,
+    parallel: bool,
+    progress: Option<&Bound<'_, PyAny>>,
+    report_offset: usize,
+    report_total: usize,
+) -> PyResult<(AggregatedStats, bool)> {
+    if num_sims == 0 {
+        return Ok((AggregatedStats::default(), false));
+    }
+
+    // 1-5% of num_sims per batch, at least 1 and never more than the whole run.
+    let batch_size = ((num_sims as f64 * 0.02).round() as usize).clamp(1, num_sims);
+
+    let mut results = Vec::with_capacity(num_sims);
+    let mut completed = 0usize;
+    let mut cancelled = false;
+    while completed < num_sims {
+        let this_batch = batch_size.min(num_sims - completed);
+        let batch_results = py.allow_threads(|| {
+            if parallel {
+                run_simulations_parallel(config, this_batch)
+            } else {
+                run_simulations_sequential(config, this_batch)
+            }
+        });
+        results.extend(batch_results);
+        completed += this_batch;
+
+        if let Some(cb) = progress {
+            let keep_going = cb.call1((report_offset + completed, report_total))?;
+            if !keep_going.extract::<bool>().unwrap_or(true) {
+                cancelled = true;
+                break;
+            }
+        }
+    }
+
+    Ok((AggregatedStats::from_results(&results), cancelled))
+}
+
+/// Single-config convenience wrapper over [`run_batched_with_progress_impl`] for `simulate` and
+/// `simulate_from_file`, where progress is just reported against this one config's `num_sims`.
+fn run_batched_with_progress(
+    py: Python<'_>,
+    config: &BuildConfig,
+    num_sims: usize,
+    parallel: bool,
+    progress: Option<&Bound<'_, PyAny>>,
+) -> PyResult<AggregatedStats> {
+    let (stats, _cancelled) = run_batched_with_progress_impl(py, config, num_sims, parallel, progress, 0, num_sims)?;
+    Ok(stats)
+}
+
 /// Python-callable simulation function
 #[pyfunction]
-#[pyo3(signature = (config_json, num_sims, parallel=false))]
-fn simulate(py: Python<'_>, config_json: &str, num_sims: usize, parallel: bool) -> PyResult<String> {
+#[pyo3(signature = (config_json, num_sims, parallel=false, progress=None))]
+fn simulate(py: Python<'_>, config_json: &str, num_sims: usize, parallel: bool, progress: Option<&Bound<'_, PyAny>>) -> PyResult<String> {
     let config: BuildConfig = serde_json::from_str(config_json)
-        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Invalid config JSON: {}", e)))?;
-    
-    // Release GIL during computation to prevent GUI freezing
-    let stats = py.allow_threads(|| run_and_aggregate(&config, num_sims, parallel));
-    
+        .map_err(|e| ConfigError::new_err(format!("Invalid config JSON: {}", e)))?;
+
+    let stats = run_batched_with_progress(py, &config, num_sims, parallel, progress)?;
+
     let result = serde_json::to_string(&stats)
-        .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Failed to serialize results: {}", e)))?;
-    
+        .map_err(|e| SimulationError::new_err(format!("Failed to serialize results: {}", e)))?;
+
     Ok(result)
 }
 
 /// Python-callable simulation function from YAML file
 #[pyfunction]
-#[pyo3(signature = (config_path, num_sims, parallel=false))]
-fn simulate_from_file(py: Python<'_>, config_path: &str, num_sims: usize, parallel: bool) -> PyResult<String> {
+#[pyo3(signature = (config_path, num_sims, parallel=false, progress=None))]
+fn simulate_from_file(py: Python<'_>, config_path: &str, num_sims: usize, parallel: bool, progress: Option<&Bound<'_, PyAny>>) -> PyResult<String> {
     let config = BuildConfig::from_file(config_path)
-        .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(format!("Failed to load config: {}", e)))?;
-    
-    // Release GIL during computation to prevent GUI freezing
-    let stats = py.allow_threads(|| run_and_aggregate(&config, num_sims, parallel));
-    
+        .map_err(|e| ConfigError::new_err(format!("Failed to load config: {}", e)))?;
+
+    let stats = run_batched_with_progress(py, &config, num_sims, parallel, progress)?;
+
     let result = serde_json::to_string(&stats)
-        .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Failed to serialize results: {}", e)))?;
-    
+        .map_err(|e| SimulationError::new_err(format!("Failed to serialize results: {}", e)))?;
+
     Ok(result)
 }
 
+/// Python-callable config validation function - returns the list of issues found (empty if
+/// the config is clean) instead of raising, so a GUI can show every problem at once.
+#[pyfunction]
+fn validate_config(config_json: &str) -> PyResult<Vec<String>> {
+    let config: BuildConfig = serde_json::from_str(config_json)
+        .map_err(|e| ConfigError::new_err(format!("Invalid config JSON: {}", e)))?;
+
+    match config.validate() {
+        Ok(()) => Ok(Vec::new()),
+        Err(issues) => Ok(issues
+            .into_iter()
+            .map(|issue| format!("[{}] {}: {}", issue.section, issue.key, issue.message))
+            .collect()),
+    }
+}
+
 /// Python-callable function to create a BuildConfig from Python dicts
 #[pyfunction]
 #[pyo3(signature = (hunter, level, stats, talents, attributes, inscryptions=None, mods=None, relics=None, gems=None))]
@@ -57,9 +147,7 @@ fn create_config(
         "borge" => HunterType::Borge,
         "ozzy" => HunterType::Ozzy,
         "knox" => HunterType::Knox,
-        _ => return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-            format!("Invalid hunter type: {}", hunter)
-        )),
+        _ => return Err(ConfigError::new_err(format!("Invalid hunter type: {}", hunter))),
     };
     
     fn pydict_to_hashmap_i32(dict: &Bound<'_, PyDict>) -> PyResult<HashMap<String, i32>> {
@@ -98,11 +186,12 @@ fn create_config(
         gems: gems.map(|d| pydict_to_hashmap_i32(d)).transpose()?.unwrap_or_default(),
         gadgets: HashMap::new(),
         bonuses: HashMap::new(),
+        ..Default::default()
     };
     
     let json = serde_json::to_string(&config)
-        .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Failed to serialize config: {}", e)))?;
-    
+        .map_err(|e| ConfigError::new_err(format!("Failed to serialize config: {}", e)))?;
+
     Ok(json)
 }
 
@@ -122,33 +211,42 @@ fn get_available_cores() -> PyResult<usize> {
 
 /// Python-callable batch simulation function - simulate multiple configs at once
 #[pyfunction]
-#[pyo3(signature = (config_jsons, num_sims, parallel=false))]
-fn simulate_batch(py: Python<'_>, config_jsons: Vec<String>, num_sims: usize, parallel: bool) -> PyResult<Vec<String>> {
+#[pyo3(signature = (config_jsons, num_sims, parallel=false, progress=None))]
+fn simulate_batch(py: Python<'_>, config_jsons: Vec<String>, num_sims: usize, parallel: bool, progress: Option<&Bound<'_, PyAny>>) -> PyResult<Vec<String>> {
     // Parse all configs first (inside GIL)
     let configs: Result<Vec<BuildConfig>, _> = config_jsons.iter()
         .map(|json| serde_json::from_str(json))
         .collect();
-    
-    let configs = configs.map_err(|e| 
-        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Invalid config JSON: {}", e))
+
+    let configs = configs.map_err(|e|
+        ConfigError::new_err(format!("Invalid config JSON: {}", e))
     )?;
-    
-    // Release GIL and run all simulations in parallel
-    let results = py.allow_threads(|| {
-        configs.iter()
-            .map(|config| run_and_aggregate(config, num_sims, parallel))
-            .collect::<Vec<_>>()
-    });
-    
+
+    // Route each config through the same batched-progress helper `simulate`/`simulate_from_file`
+    // use, so a single config's multi-million-run sweep still ticks progress and can be
+    // cancelled mid-run instead of only between configs. report_total covers every sim across
+    // every config so the callback sees one continuous sweep, not a per-config reset.
+    let total = configs.len();
+    let total_sims = total * num_sims;
+    let mut results = Vec::with_capacity(total);
+    for (i, config) in configs.iter().enumerate() {
+        let (stats, cancelled) = run_batched_with_progress_impl(py, config, num_sims, parallel, progress, i * num_sims, total_sims)?;
+        results.push(stats);
+
+        if cancelled {
+            break;
+        }
+    }
+
     // Serialize results (inside GIL)
     let json_results: Result<Vec<String>, _> = results.iter()
         .map(|stats| serde_json::to_string(stats))
         .collect();
-    
-    let json_results = json_results.map_err(|e| 
-        PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Failed to serialize results: {}", e))
+
+    let json_results = json_results.map_err(|e|
+        SimulationError::new_err(format!("Failed to serialize results: {}", e))
     )?;
-    
+
     Ok(json_results)
 }
 
@@ -217,7 +315,37 @@ fn generate_builds(
         let gate: i32 = value.extract()?;
         gates_map.insert(name, gate);
     }
-    
+
+    // Dependencies/gates/exclusions only make sense for attributes that were actually declared
+    for (attr_name, deps) in &deps_map {
+        if !attr_map.contains_key(attr_name) {
+            return Err(BuildGenerationError::new_err(format!(
+                "Dependency declared for unknown attribute: {}", attr_name
+            )));
+        }
+        for dep_name in deps.keys() {
+            if !attr_map.contains_key(dep_name) {
+                return Err(BuildGenerationError::new_err(format!(
+                    "Dependency on unknown attribute: {}", dep_name
+                )));
+            }
+        }
+    }
+    for attr_name in gates_map.keys() {
+        if !attr_map.contains_key(attr_name) {
+            return Err(BuildGenerationError::new_err(format!(
+                "Point gate declared for unknown attribute: {}", attr_name
+            )));
+        }
+    }
+    for (a, b) in &attribute_exclusions {
+        if !attr_map.contains_key(a) || !attr_map.contains_key(b) {
+            return Err(BuildGenerationError::new_err(format!(
+                "Exclusion declared for unknown attribute pair: ({}, {})", a, b
+            )));
+        }
+    }
+
     // Create generator
     let generator = BuildGenerator::new(
         level,
@@ -228,21 +356,29 @@ fn generate_builds(
         attribute_exclusions,
     );
     
-    // Generate builds (release GIL)
+    // Generate builds (release GIL). Equipment loadouts aren't exposed over this binding yet,
+    // so only the talent/attribute portions of each build are returned.
     let builds = py.allow_threads(|| generator.generate_builds(count));
-    
-    Ok(builds)
+
+    Ok(builds.into_iter().map(|(talents, attrs, _)| (talents, attrs)).collect())
 }
 
 /// Python module definition
 #[pymodule]
-fn hunter_sim_lib(_py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
+fn hunter_sim_lib(py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(simulate, m)?)?;
     m.add_function(wrap_pyfunction!(simulate_from_file, m)?)?;
     m.add_function(wrap_pyfunction!(simulate_batch, m)?)?;
     m.add_function(wrap_pyfunction!(create_config, m)?)?;
+    m.add_function(wrap_pyfunction!(validate_config, m)?)?;
     m.add_function(wrap_pyfunction!(get_thread_count, m)?)?;
     m.add_function(wrap_pyfunction!(get_available_cores, m)?)?;
     m.add_function(wrap_pyfunction!(generate_builds, m)?)?;
+
+    m.add("HunterSimError", py.get_type::<HunterSimError>())?;
+    m.add("ConfigError", py.get_type::<ConfigError>())?;
+    m.add("SimulationError", py.get_type::<SimulationError>())?;
+    m.add("BuildGenerationError", py.get_type::<BuildGenerationError>())?;
+
     Ok(())
 }
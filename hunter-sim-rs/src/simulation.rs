@@ -1,8 +1,9 @@
 //! Core simulation engine
 
 use crate::config::BuildConfig;
-use crate::enemy::Enemy;
+use crate::enemy::{DotKind, Element, Enemy, StackingRule, StatusKind};
 use crate::hunter::Hunter;
+use crate::stage_profile::StageProfile;
 use crate::stats::{AggregatedStats, SimResult};
 use rand::rngs::SmallRng;
 use rand::{Rng, SeedableRng};
@@ -65,9 +66,47 @@ impl Ord for Event {
 #[derive(Debug, Clone, Copy)]
 enum Action {
     HunterAttack,
-    EnemyAttack,
-    EnemySpecial,
+    /// `idx` indexes into the current stage's `Vec<Enemy>`.
+    EnemyAttack { idx: usize },
+    EnemySpecial { idx: usize },
     Regen,
+    /// One tick of `enemies[idx].dots[dot_index]`; see `Enemy::tick_dot`.
+    DotTick { idx: usize, dot_index: usize },
+    /// A projectile's damage landing on `enemies[idx]` after `hunter.projectile_flight_time`.
+    /// `enemies` is a fixed-size `Vec` for the lifetime of a wave and dead slots are never reused
+    /// by a new enemy instance, so `!enemies[idx].is_dead()` alone is enough to discard a bullet
+    /// whose target died (or whose wave ended) before it landed.
+    DeferredDamage { idx: usize, amount: f64, is_crit: bool },
+}
+
+/// A single salvo projectile's damage, deferred until it "lands" instead of applying instantly,
+/// mirroring Hercules' `delay_damage_ers` travel-time model. `target` is the index into the
+/// stage's `Vec<Enemy>` it's bound for, so projectiles fired at a wave can be allocated across
+/// several targets.
+struct DeferredBullet {
+    target: usize,
+    amount: f64,
+    is_crit: bool,
+}
+
+/// What an attack produced besides its already-applied direct damage: a DoT to start ticking on
+/// its primary target (`usize` is the target's index into the stage's `Vec<Enemy>`), and/or
+/// salvo projectiles still in flight.
+#[derive(Default)]
+struct AttackOutcome {
+    dot: Option<(usize, DotApplication)>,
+    deferred: Vec<DeferredBullet>,
+}
+
+/// Parameters for a DoT to apply after an attack resolves, returned by attack functions so the
+/// caller (which owns `queue`/`elapsed_time`) can schedule its first `Action::DotTick`.
+struct DotApplication {
+    kind: DotKind,
+    damage_per_tick: f64,
+    ticks: u32,
+    tick_interval: f64,
+    refreshable: bool,
+    stacking: bool,
 }
 
 /// Run a single simulation
@@ -76,211 +115,355 @@ pub fn run_simulation(config: &BuildConfig) -> SimResult {
     run_simulation_with_rng(config, &mut rng)
 }
 
+/// Pick up to `count` other living enemies for a splash hit to land on, starting just after
+/// `primary` and wrapping around the wave - mirrors `foreachinrange` gathering nearby `BCT_ENEMY`
+/// targets rather than favoring any particular slot.
+fn pick_splash_targets(enemies: &[Enemy], primary: usize, count: i32) -> Vec<usize> {
+    if count <= 0 || enemies.len() < 2 {
+        return Vec::new();
+    }
+    let n = enemies.len();
+    (1..n)
+        .map(|offset| (primary + offset) % n)
+        .filter(|&idx| !enemies[idx].is_dead())
+        .take(count as usize)
+        .collect()
+}
+
 /// Run a simulation with a specific RNG (for deterministic testing)
 pub fn run_simulation_with_rng(config: &BuildConfig, rng: &mut impl Rng) -> SimResult {
+    run_simulation_with_rng_profiled(config, rng, None)
+}
+
+/// Same as `run_simulation_with_rng`, additionally feeding each completed stage's total loot into
+/// `profile` (if given) via `StageProfile::record`. Takes the profile by reference rather than
+/// returning per-stage samples so a batch run can keep one running `StageProfile` across thousands
+/// of simulations without ever holding more than the current run's `SimResult` in memory.
+pub fn run_simulation_with_rng_profiled(
+    config: &BuildConfig,
+    rng: &mut impl Rng,
+    mut profile: Option<&mut StageProfile>,
+) -> SimResult {
     let mut hunter = Hunter::from_config(config);
     let mut elapsed_time: f64 = 0.0;
     let mut total_loot: f64 = 0.0;
-    
+
     let mut queue: BinaryHeap<Event> = BinaryHeap::new();
-    
+
     // Main simulation loop - progress through stages
     'stages: loop {
         let stage = hunter.current_stage;
-        
+
         // Spawn enemies for this stage
-        let enemies = if stage % 100 == 0 && stage > 0 {
+        let weaknesses = config.get_enemy_weaknesses(stage);
+        let immunities = config.get_enemy_immunities(stage);
+        // An active trample/decay mod takes over the enemy's defense type entirely, ahead of
+        // `forced_enemy_element`, so a build's `EffectivenessTable` can price in a matchup
+        // against the mod itself rather than the stage's usual elemental cycle.
+        let forced_element = if hunter.has_trample {
+            Some(Element::Trample)
+        } else if hunter.has_decay {
+            Some(Element::Decay)
+        } else {
+            config.forced_enemy_element.as_deref().map(Element::from_str)
+        };
+        let effectiveness_table = config.effectiveness_table.clone();
+        let mut enemies = if stage % 100 == 0 && stage > 0 {
             // Boss stage
-            vec![Enemy::new_boss(stage, hunter.hunter_type)]
+            vec![Enemy::new_boss_with_elements(stage, hunter.hunter_type, weaknesses, immunities, forced_element, effectiveness_table)]
         } else {
-            // Regular stage - 10 enemies
-            (1..=10).map(|i| Enemy::new(i, stage, hunter.hunter_type)).collect()
+            // Regular stage - all 10 enemies are alive and act concurrently
+            (1..=10)
+                .map(|i| Enemy::new_with_elements(i, stage, hunter.hunter_type, weaknesses.clone(), immunities.clone(), forced_element, effectiveness_table.clone()))
+                .collect()
         };
-        
-        // Fight each enemy in the stage
-        for mut enemy in enemies {
-            queue.clear();
-            
-            // Queue initial events
-            queue.push(Event { time: elapsed_time + hunter.speed, priority: 1, action: Action::HunterAttack });
-            queue.push(Event { time: elapsed_time + enemy.speed, priority: 2, action: Action::EnemyAttack });
-            queue.push(Event { time: elapsed_time + 1.0, priority: 3, action: Action::Regen });
-            
+        // Tracks which enemies have already had `on_kill` fired for them, since a splash hit or a
+        // salvo landing can kill more than one enemy within the same event.
+        let mut killed = vec![false; enemies.len()];
+
+        queue.clear();
+
+        // Queue initial events
+        queue.push(Event { time: elapsed_time + hunter.speed(), priority: 1, action: Action::HunterAttack });
+        queue.push(Event { time: elapsed_time + 1.0, priority: 3, action: Action::Regen });
+        for (idx, enemy) in enemies.iter().enumerate() {
+            queue.push(Event { time: elapsed_time + enemy.get_speed(), priority: 2, action: Action::EnemyAttack { idx } });
             if enemy.has_secondary {
-                queue.push(Event { time: elapsed_time + enemy.speed2, priority: 2, action: Action::EnemySpecial });
+                queue.push(Event { time: elapsed_time + enemy.get_speed2(), priority: 2, action: Action::EnemySpecial { idx } });
             }
-            
-            // Apply on-spawn effects
-            apply_spawn_effects(&mut hunter, &mut enemy, rng);
-            
-            // Combat loop
-            while !enemy.is_dead() && !hunter.is_dead() {
+        }
+
+        // Apply on-spawn effects
+        apply_spawn_effects(&mut hunter, &mut enemies, rng);
+
+        // Combat loop - runs until every enemy in the wave is dead or the hunter dies; a revive
+        // re-enters with the same wave and queue rather than respawning it.
+        'wave: loop {
+            while enemies.iter().any(|e| !e.is_dead()) && !hunter.is_dead() {
                 let event = match queue.pop() {
                     Some(e) => e,
                     None => break,
                 };
-                
+
                 elapsed_time = event.time;
-                
+                for enemy in enemies.iter_mut() {
+                    enemy.tick_statuses(elapsed_time);
+                }
+                hunter.tick_effects(elapsed_time);
+
                 match event.action {
                     Action::HunterAttack => {
-                        hunter_attack(&mut hunter, &mut enemy, rng);
-                        queue.push(Event { 
-                            time: elapsed_time + hunter.speed, 
-                            priority: 1, 
-                            action: Action::HunterAttack 
+                        // Target the first living enemy, the way the single-target combat loop
+                        // always fought enemies in spawn order.
+                        if let Some(target) = enemies.iter().position(|e| !e.is_dead()) {
+                            let outcome = hunter_attack(&mut hunter, &mut enemies, target, rng, elapsed_time);
+                            if let Some((dot_target, dot)) = outcome.dot {
+                                let (dot_index, is_new) = enemies[dot_target].apply_dot(
+                                    dot.kind,
+                                    dot.damage_per_tick,
+                                    dot.ticks,
+                                    dot.tick_interval,
+                                    dot.refreshable,
+                                    dot.stacking,
+                                );
+                                if is_new {
+                                    queue.push(Event {
+                                        time: elapsed_time + dot.tick_interval,
+                                        priority: 2,
+                                        action: Action::DotTick { idx: dot_target, dot_index },
+                                    });
+                                }
+                            }
+                            for bullet in outcome.deferred {
+                                queue.push(Event {
+                                    time: elapsed_time + hunter.projectile_flight_time,
+                                    priority: 2,
+                                    action: Action::DeferredDamage {
+                                        idx: bullet.target,
+                                        amount: bullet.amount,
+                                        is_crit: bullet.is_crit,
+                                    },
+                                });
+                            }
+                        }
+                        queue.push(Event {
+                            time: elapsed_time + hunter.speed(),
+                            priority: 1,
+                            action: Action::HunterAttack
                         });
                     }
-                    Action::EnemyAttack => {
-                        if !enemy.is_stunned || elapsed_time >= enemy.stun_end_time {
-                            enemy.is_stunned = false;
-                            enemy_attack(&mut hunter, &mut enemy, rng);
-                            if !enemy.is_dead() {
-                                queue.push(Event { 
-                                    time: elapsed_time + enemy.speed, 
-                                    priority: 2, 
-                                    action: Action::EnemyAttack 
+                    Action::EnemyAttack { idx } => {
+                        let enemy = &mut enemies[idx];
+                        // A stunned enemy skips this attack but keeps its attack timer running,
+                        // rather than going silent for the rest of the fight once stunned once.
+                        let stunned = enemy.has_status(StatusKind::Stun);
+                        let alive = !enemy.is_dead();
+                        if alive && !stunned {
+                            enemy_attack(&mut hunter, &mut enemies[idx], rng, elapsed_time);
+                        }
+                        if alive && !enemies[idx].is_dead() {
+                            let next_time = elapsed_time + enemies[idx].get_speed();
+                            queue.push(Event { time: next_time, priority: 2, action: Action::EnemyAttack { idx } });
+                        }
+                    }
+                    Action::EnemySpecial { idx } => {
+                        let enemy = &mut enemies[idx];
+                        if enemy.is_boss {
+                            if !enemy.has_status(StatusKind::Stun) {
+                                enemy.add_enrage();
+                            }
+                            queue.push(Event {
+                                time: elapsed_time + enemy.get_speed2(),
+                                priority: 2,
+                                action: Action::EnemySpecial { idx }
+                            });
+                        }
+                    }
+                    Action::DotTick { idx, dot_index } => {
+                        let enemy = &mut enemies[idx];
+                        if let Some(damage) = enemy.tick_dot(dot_index) {
+                            hunter.result.damage += damage;
+                            hunter.result.dot_damage += damage;
+                            if enemy.dots[dot_index].ticks_remaining > 0 {
+                                queue.push(Event {
+                                    time: elapsed_time + enemy.dots[dot_index].tick_interval,
+                                    priority: 2,
+                                    action: Action::DotTick { idx, dot_index },
                                 });
                             }
                         }
                     }
-                    Action::EnemySpecial => {
-                        if enemy.is_boss && !enemy.is_stunned {
-                            enemy.add_enrage();
-                            queue.push(Event { 
-                                time: elapsed_time + enemy.speed2, 
-                                priority: 2, 
-                                action: Action::EnemySpecial 
-                            });
+                    Action::DeferredDamage { idx, amount, is_crit: _ } => {
+                        if !enemies[idx].is_dead() {
+                            // Knox's whole attack kit lands here, so this is also where a boss's
+                            // thorns reflect has to be rolled - `hunter_attack`'s primary hit isn't
+                            // reached for a salvo-based hunter at all.
+                            let result = enemies[idx].take_damage_with_reflect(amount, &hunter.damage_type, &hunter.race_size_bonus, rng);
+                            hunter.result.damage += result.applied;
+                            hunter.result.elemental_damage += result.elemental_delta;
+
+                            if hunter.lifesteal() > 0.0 {
+                                let healed = result.applied * hunter.lifesteal();
+                                hunter.hp = (hunter.hp + healed).min(hunter.max_hp());
+                                hunter.result.lifesteal += healed;
+                            }
+
+                            if result.reflected > 0.0 {
+                                hunter.hp = (hunter.hp - result.reflected).max(0.0);
+                                hunter.result.damage_taken += result.reflected;
+                                hunter.result.reflected_damage_taken += result.reflected;
+                                hunter.result.damage_timeline.push((elapsed_time, result.reflected));
+                            }
                         }
                     }
                     Action::Regen => {
                         hunter.regen_hp();
-                        enemy.regen_hp();
-                        queue.push(Event { 
-                            time: elapsed_time + 1.0, 
-                            priority: 3, 
-                            action: Action::Regen 
+                        for enemy in enemies.iter_mut() {
+                            enemy.regen_hp();
+                        }
+                        queue.push(Event {
+                            time: elapsed_time + 1.0,
+                            priority: 3,
+                            action: Action::Regen
                         });
                     }
                 }
+
+                // Fire on-kill effects for any enemy that died from this event, even if several died
+                // at once (e.g. a splash hit sweeping a near-dead wave).
+                for idx in 0..enemies.len() {
+                    if enemies[idx].is_dead() && !killed[idx] {
+                        killed[idx] = true;
+                        on_kill(&mut hunter, rng, elapsed_time);
+                        hunter.result.kills += 1;
+                    }
+                }
             }
-            
+
             // Check if hunter died
             if hunter.is_dead() {
                 if hunter.try_revive() {
-                    // Revived, continue fighting
-                    continue;
+                    // Revived, continue fighting the same wave rather than respawning it
+                    continue 'wave;
                 } else {
                     // Dead for real, end simulation
                     break 'stages;
                 }
             }
-            
-            // Enemy killed
-            on_kill(&mut hunter, rng);
-            hunter.result.kills += 1;
+            break 'wave;
         }
-        
+
         // Stage complete - calculate per-resource loot
-        on_stage_complete(&mut hunter, rng);
+        on_stage_complete(&mut hunter, rng, elapsed_time);
         let (mat1, mat2, mat3, xp) = hunter.calculate_loot();
         hunter.result.loot_common += mat1;
         hunter.result.loot_uncommon += mat2;
         hunter.result.loot_rare += mat3;
         hunter.result.total_xp += xp;
-        total_loot += mat1 + mat2 + mat3;
+        let stage_loot = mat1 + mat2 + mat3;
+        total_loot += stage_loot;
+        if let Some(profile) = profile.as_deref_mut() {
+            profile.record(stage, stage_loot);
+        }
         hunter.current_stage += 1;
-        
+
         // Safety check - don't run forever
         if hunter.current_stage > 1000 {
             break;
         }
     }
-    
+
     // Finalize results
     hunter.result.final_stage = hunter.current_stage;
     hunter.result.elapsed_time = elapsed_time;
     hunter.result.total_loot = total_loot;
-    
+    hunter.result.max_hp = hunter.max_hp();
+
     hunter.result
 }
 
-/// Apply effects when an enemy spawns
-fn apply_spawn_effects(hunter: &mut Hunter, enemy: &mut Enemy, _rng: &mut impl Rng) {
-    // Presence of God - instant damage on spawn
-    if hunter.presence_of_god > 0 {
-        let pog_damage = hunter.power * 0.1 * hunter.presence_of_god as f64;
-        enemy.take_damage(pog_damage);
-        hunter.result.damage += pog_damage;
-    }
-    
-    // Omen of Defeat - reduce enemy stats
-    if hunter.omen_of_defeat > 0 {
-        let reduction = 1.0 - (0.02 * hunter.omen_of_defeat as f64);
-        enemy.power *= reduction;
-        enemy.hp *= reduction;
-        enemy.max_hp *= reduction;
-    }
-    
-    // Soul of Snek (Ozzy) - reduce enemy regen by 8.8% per level
-    if hunter.soul_of_snek > 0 {
-        let regen_reduction = 1.0 - (0.088 * hunter.soul_of_snek as f64);
-        enemy.regen *= regen_reduction.max(0.0);
-    }
-    
-    // Gift of Medusa (Ozzy) - 5% of hunter max HP as enemy -regen per level
-    if hunter.gift_of_medusa > 0 {
-        let anti_regen = hunter.max_hp * 0.05 * hunter.gift_of_medusa as f64;
-        enemy.regen = (enemy.regen - anti_regen).max(0.0);
+/// Apply effects when a wave of enemies spawns
+fn apply_spawn_effects(hunter: &mut Hunter, enemies: &mut [Enemy], _rng: &mut impl Rng) {
+    for enemy in enemies.iter_mut() {
+        // Presence of God - instant damage on spawn
+        if hunter.presence_of_god > 0 {
+            let pog_damage = hunter.power() * 0.1 * hunter.presence_of_god as f64;
+            enemy.take_damage_typed(pog_damage, &hunter.damage_type);
+            hunter.result.damage += pog_damage;
+        }
+
+        // Omen of Defeat - reduce enemy stats
+        if hunter.omen_of_defeat > 0 {
+            let reduction = 1.0 - (0.02 * hunter.omen_of_defeat as f64);
+            enemy.power *= reduction;
+            enemy.hp *= reduction;
+            enemy.max_hp *= reduction;
+        }
+
+        // Soul of Snek (Ozzy) - reduce enemy regen by 8.8% per level
+        if hunter.soul_of_snek > 0 {
+            let regen_reduction = 1.0 - (0.088 * hunter.soul_of_snek as f64);
+            enemy.regen *= regen_reduction.max(0.0);
+        }
+
+        // Gift of Medusa (Ozzy) - 5% of hunter max HP as enemy -regen per level
+        if hunter.gift_of_medusa > 0 {
+            let anti_regen = hunter.max_hp() * 0.05 * hunter.gift_of_medusa as f64;
+            enemy.regen = (enemy.regen - anti_regen).max(0.0);
+        }
     }
 }
 
 /// Handle on-kill effects for hunter
-fn on_kill(hunter: &mut Hunter, rng: &mut impl Rng) {
+fn on_kill(hunter: &mut Hunter, rng: &mut impl Rng, now: f64) {
     // Trickster's Boon (Ozzy) - 50% of effect chance to gain a trickster charge
-    if hunter.tricksters_boon > 0 && rng.gen::<f64>() < hunter.effect_chance / 2.0 {
-        hunter.trickster_charges += 1;
+    if hunter.tricksters_boon > 0 && rng.gen::<f64>() < hunter.effect_chance() / 2.0 {
+        hunter.apply_effect(StatusKind::TricksterCharges, now, f64::INFINITY, 0.0, i32::MAX, StackingRule::AddStack);
         hunter.result.effect_procs += 1;
     }
     
     // Unfair Advantage (Ozzy/shared) - effect chance to heal 2% max HP per level
-    if hunter.unfair_advantage > 0 && rng.gen::<f64>() < hunter.effect_chance {
-        let heal_amount = hunter.max_hp * 0.02 * hunter.unfair_advantage as f64;
-        hunter.hp = (hunter.hp + heal_amount).min(hunter.max_hp);
+    if hunter.unfair_advantage > 0 && rng.gen::<f64>() < hunter.effect_chance() {
+        let heal_amount = hunter.max_hp() * 0.02 * hunter.unfair_advantage as f64;
+        hunter.hp = (hunter.hp + heal_amount).min(hunter.max_hp());
         hunter.result.unfair_advantage_healing += heal_amount;
         hunter.result.effect_procs += 1;
         
         // Vectid Elixir (Ozzy) - empowered regen for 5 ticks after Unfair Advantage
         if hunter.vectid_elixir > 0 {
-            hunter.empowered_regen += 5;
+            hunter.apply_timed_status(StatusKind::EmpoweredRegen, now, 5.0);
         }
     }
     
     // Life of the Hunt (Borge/shared) - effect chance to heal 1% max HP per level
-    if hunter.life_of_the_hunt > 0 && rng.gen::<f64>() < hunter.effect_chance {
-        let heal_amount = hunter.max_hp * 0.01 * hunter.life_of_the_hunt as f64;
-        hunter.hp = (hunter.hp + heal_amount).min(hunter.max_hp);
+    if hunter.life_of_the_hunt > 0 && rng.gen::<f64>() < hunter.effect_chance() {
+        let heal_amount = hunter.max_hp() * 0.01 * hunter.life_of_the_hunt as f64;
+        hunter.hp = (hunter.hp + heal_amount).min(hunter.max_hp());
         hunter.result.life_of_the_hunt_healing += heal_amount;
         hunter.result.effect_procs += 1;
     }
 }
 
 /// Handle on-stage-complete effects for hunter
-fn on_stage_complete(hunter: &mut Hunter, rng: &mut impl Rng) {
+fn on_stage_complete(hunter: &mut Hunter, rng: &mut impl Rng, now: f64) {
     // Calypso's Advantage (Knox) - chance to gain Hundred Souls stack on stage clear
-    if hunter.calypsos_advantage > 0 && rng.gen::<f64>() < hunter.effect_chance * 2.5 {
+    if hunter.calypsos_advantage > 0 && rng.gen::<f64>() < hunter.effect_chance() * 2.5 {
         // Max stacks = 100 base + dead_men_tell_no_tales * 10
         let max_stacks = 100 + hunter.soul_amplification * 10;
-        if hunter.hundred_souls_stacks < max_stacks {
-            hunter.hundred_souls_stacks += 1;
+        if hunter.stack_count(StatusKind::HundredSouls) < max_stacks {
+            hunter.apply_effect(StatusKind::HundredSouls, now, f64::INFINITY, 0.0, max_stacks, StackingRule::AddStack);
             hunter.result.effect_procs += 1;
         }
     }
 }
 
-/// Knox salvo attack - fires multiple projectiles per attack
-fn knox_salvo_attack(hunter: &mut Hunter, enemy: &mut Enemy, rng: &mut impl Rng, effective_power: f64) {
+/// Knox salvo attack - fires multiple projectiles per attack, round-robined across the primary
+/// target and any splash targets (Hercules-style area target selection) so a salvo against a
+/// crowded wave doesn't dump every bullet onto one enemy. Each bullet's damage is returned as a
+/// `DeferredBullet` rather than applied immediately, so the caller can schedule it to land after
+/// `hunter.projectile_flight_time` (giving overkill/low-HP enemies a chance to die before every
+/// outstanding bullet connects).
+fn knox_salvo_attack(hunter: &mut Hunter, enemies: &mut [Enemy], target: usize, rng: &mut impl Rng, effective_power: f64, now: f64) -> Vec<DeferredBullet> {
     // Calculate number of projectiles in this salvo
     let mut num_projectiles = hunter.salvo_projectiles;
     
@@ -301,77 +484,82 @@ fn knox_salvo_attack(hunter: &mut Hunter, enemy: &mut Enemy, rng: &mut impl Rng,
         }
     }
     
-    let mut total_damage = 0.0;
+    // Round-robin targets: the primary target, then any splash targets, repeating if there are
+    // more projectiles than targets.
+    let mut targets = vec![target];
+    targets.extend(pick_splash_targets(enemies, target, hunter.splash_count));
+
     let base_projectiles = hunter.salvo_projectiles as f64;
-    
+    let mut bullets = Vec::with_capacity(num_projectiles as usize);
+
     for i in 0..num_projectiles {
         // Each projectile deals a portion of total power
         let mut bullet_damage = effective_power / base_projectiles;
-        
+        let mut bullet_crit = false;
+
         // Check for charge (Knox's crit equivalent)
-        if rng.gen::<f64>() < hunter.charge_chance {
-            bullet_damage *= 1.0 + hunter.charge_gained;
+        if rng.gen::<f64>() < hunter.charge_chance() {
+            bullet_damage *= 1.0 + hunter.charge_gained();
             hunter.result.crits += 1;
+            bullet_crit = true;
         }
-        
+
         // Finishing Move on last bullet - chance for bonus damage
         if i == num_projectiles - 1 && hunter.finishing_move > 0 {
-            if rng.gen::<f64>() < hunter.effect_chance * 2.0 {
-                bullet_damage *= hunter.special_damage;  // special_damage = 1.0 + 0.2 * finishing_move
+            if rng.gen::<f64>() < hunter.effect_chance() * 2.0 {
+                bullet_damage *= hunter.special_damage();  // special_damage = 1.0 + 0.2 * finishing_move
                 hunter.result.effect_procs += 1;
             }
         }
-        
-        total_damage += bullet_damage;
-    }
-    
-    // Apply damage
-    let actual_damage = enemy.take_damage(total_damage);
-    hunter.result.damage += actual_damage;
-    
-    // Lifesteal
-    if hunter.lifesteal > 0.0 {
-        let healed = actual_damage * hunter.lifesteal;
-        hunter.hp = (hunter.hp + healed).min(hunter.max_hp);
-        hunter.result.lifesteal += healed;
+
+        let bullet_target = targets[i as usize % targets.len()];
+        bullets.push(DeferredBullet { target: bullet_target, amount: bullet_damage, is_crit: bullet_crit });
     }
-    
-    // Effect proc (stun)
-    if rng.gen::<f64>() < hunter.effect_chance {
+
+    // Effect proc (stun) - rolled at the moment of firing, not when the bullets land. Only the
+    // primary target is stunned; splash targets just take splash damage.
+    if rng.gen::<f64>() < hunter.effect_chance() {
         hunter.result.effect_procs += 1;
-        let stun_duration = 1.0 + 0.2 * hunter.effect_chance;
-        let actual_stun = if enemy.is_boss { stun_duration * 0.5 } else { stun_duration };
-        enemy.is_stunned = true;
-        enemy.stun_end_time = hunter.result.elapsed_time + actual_stun;
-        hunter.result.stun_duration_inflicted += actual_stun;
+        let stun_duration = 1.0 + 0.2 * hunter.effect_chance();
+        let primary = &mut enemies[target];
+        let actual_stun = if primary.is_boss { stun_duration * 0.5 } else { stun_duration };
+        if primary.apply_status(StatusKind::Stun, 0.0, actual_stun, now, actual_stun, rng) {
+            hunter.result.stun_duration_inflicted += actual_stun;
+        }
     }
+
+    bullets
 }
 
-/// Hunter attacks enemy
-fn hunter_attack(hunter: &mut Hunter, enemy: &mut Enemy, rng: &mut impl Rng) {
+/// Hunter attacks `enemies[target]`. Returns a DoT to apply (currently: a bleed on crit) and/or
+/// salvo projectiles still in flight, so the caller (which owns `queue`/`elapsed_time`) can
+/// schedule them. If the hunter has splash configured (`splash_count`/`splash_fraction`) and more
+/// than one enemy is alive, a fraction of the hit also lands on nearby living targets.
+fn hunter_attack(hunter: &mut Hunter, enemies: &mut [Enemy], target: usize, rng: &mut impl Rng, now: f64) -> AttackOutcome {
     hunter.result.attacks += 1;
     
     // Calculate effective power (base + deal_with_death per revive used)
-    let mut effective_power = hunter.power;
+    let mut effective_power = hunter.power();
     if hunter.deal_with_death > 0 && hunter.revive_count > 0 {
         effective_power *= 1.0 + (hunter.deal_with_death as f64 * 0.02 * hunter.revive_count as f64);
     }
     
     // Born for Battle (Borge) - +0.1% power per 1% missing HP
     if hunter.born_for_battle > 0 {
-        let missing_hp_pct = 1.0 - (hunter.hp / hunter.max_hp);
+        let missing_hp_pct = 1.0 - (hunter.hp / hunter.max_hp());
         effective_power *= 1.0 + (missing_hp_pct * hunter.born_for_battle as f64 * 0.001);
     }
     
     // Hundred Souls power bonus (Knox) - +0.5% per stack, boosted by soul_amplification
-    if hunter.hundred_souls_stacks > 0 {
+    let hundred_souls_stacks = hunter.stack_count(StatusKind::HundredSouls);
+    if hundred_souls_stacks > 0 {
         let souls_multiplier = 0.005 * (1.0 + hunter.soul_amplification as f64 * 0.01);
-        effective_power *= 1.0 + (hunter.hundred_souls_stacks as f64 * souls_multiplier);
+        effective_power *= 1.0 + (hundred_souls_stacks as f64 * souls_multiplier);
     }
     
     // Calculate effective crit chance (base + cycle_of_death per revive used)
-    let mut effective_crit_chance = hunter.special_chance;
-    let mut effective_crit_dmg = hunter.special_damage;
+    let mut effective_crit_chance = hunter.special_chance();
+    let mut effective_crit_dmg = hunter.special_damage();
     if hunter.cycle_of_death > 0 && hunter.revive_count > 0 {
         effective_crit_chance += hunter.cycle_of_death as f64 * 0.023 * hunter.revive_count as f64;
         effective_crit_dmg += hunter.cycle_of_death as f64 * 0.02 * hunter.revive_count as f64;
@@ -379,12 +567,15 @@ fn hunter_attack(hunter: &mut Hunter, enemy: &mut Enemy, rng: &mut impl Rng) {
     
     // Knox salvo attack mechanics
     if hunter.salvo_projectiles > 0 {
-        knox_salvo_attack(hunter, enemy, rng, effective_power);
-        return;
+        let deferred = knox_salvo_attack(hunter, enemies, target, rng, effective_power, now);
+        return AttackOutcome { dot: None, deferred };
     }
-    
+    let enemy = &mut enemies[target];
+
     // Check for crit
+    let mut is_crit = false;
     let base_damage = if rng.gen::<f64>() < effective_crit_chance {
+        is_crit = true;
         hunter.result.crits += 1;
         let crit_dmg = effective_power * effective_crit_dmg;
         hunter.result.extra_damage_from_crits += crit_dmg - effective_power;
@@ -394,10 +585,9 @@ fn hunter_attack(hunter: &mut Hunter, enemy: &mut Enemy, rng: &mut impl Rng) {
     };
     
     // Apply decay stacks bonus (Ozzy Crippling Shots) - consume stacks
-    let decay_bonus = if hunter.decay_stacks > 0 {
-        let bonus = base_damage * 0.03 * hunter.decay_stacks as f64;
-        hunter.decay_stacks = 0;  // Consume stacks
-        bonus
+    let decay_stacks = hunter.consume_stacks(StatusKind::DecayStacks);
+    let decay_bonus = if decay_stacks > 0 {
+        base_damage * 0.03 * decay_stacks as f64
     } else {
         0.0
     };
@@ -437,14 +627,24 @@ fn hunter_attack(hunter: &mut Hunter, enemy: &mut Enemy, rng: &mut impl Rng) {
     
     let total_damage = base_damage + decay_bonus + omen_decay_damage + multistrike_bonus;
     
-    // Apply damage
-    let actual_damage = enemy.take_damage(total_damage);
+    // Apply damage. Bosses can roll a thorns-style reflect on this hit; whatever gets reflected
+    // is applied straight back to the hunter below.
+    let result = enemy.take_damage_with_reflect(total_damage, &hunter.damage_type, &hunter.race_size_bonus, rng);
+    let actual_damage = result.applied;
     hunter.result.damage += actual_damage;
-    
+    hunter.result.elemental_damage += result.elemental_delta;
+
+    if result.reflected > 0.0 {
+        hunter.hp = (hunter.hp - result.reflected).max(0.0);
+        hunter.result.damage_taken += result.reflected;
+        hunter.result.reflected_damage_taken += result.reflected;
+        hunter.result.damage_timeline.push((now, result.reflected));
+    }
+
     // Echo Bullets (Ozzy) - chance for extra shot
-    if hunter.echo_bullets > 0 && rng.gen::<f64>() < hunter.effect_chance {
+    if hunter.echo_bullets > 0 && rng.gen::<f64>() < hunter.effect_chance() {
         hunter.result.echo_bullets += 1;
-        let echo_dmg = hunter.power * hunter.echo_bullets as f64 * 0.05;  // 0.05x per level
+        let echo_dmg = hunter.power() * hunter.echo_bullets as f64 * 0.05;  // 0.05x per level
         let echo_decay = if hunter.omen_of_decay > 0 {
             let decay_pct = hunter.omen_of_decay as f64 * 0.008;
             let bonus = enemy.hp * decay_pct;
@@ -453,9 +653,16 @@ fn hunter_attack(hunter: &mut Hunter, enemy: &mut Enemy, rng: &mut impl Rng) {
             0.0
         };
         let echo_total = echo_dmg + echo_decay;
-        let echo_actual = enemy.take_damage(echo_total);
-        hunter.result.damage += echo_actual;
-        
+        let echo_result = enemy.take_damage_with_reflect(echo_total, &hunter.damage_type, &hunter.race_size_bonus, rng);
+        hunter.result.damage += echo_result.applied;
+        hunter.result.elemental_damage += echo_result.elemental_delta;
+        if echo_result.reflected > 0.0 {
+            hunter.hp = (hunter.hp - echo_result.reflected).max(0.0);
+            hunter.result.damage_taken += echo_result.reflected;
+            hunter.result.reflected_damage_taken += echo_result.reflected;
+            hunter.result.damage_timeline.push((now, echo_result.reflected));
+        }
+
         // Echo can trigger its own multistrike
         if hunter.multistriker > 0 && rng.gen::<f64>() < 0.1 + 0.05 * hunter.multistriker as f64 {
             hunter.result.multistrikes += 1;
@@ -468,33 +675,39 @@ fn hunter_attack(hunter: &mut Hunter, enemy: &mut Enemy, rng: &mut impl Rng) {
                 0.0
             };
             let echo_ms_total = echo_ms + echo_ms_omen;
-            let echo_ms_actual = enemy.take_damage(echo_ms_total);
-            hunter.result.damage += echo_ms_actual;
-            hunter.result.extra_damage_from_ms += echo_ms_actual;
+            let echo_ms_result = enemy.take_damage_with_reflect(echo_ms_total, &hunter.damage_type, &hunter.race_size_bonus, rng);
+            hunter.result.damage += echo_ms_result.applied;
+            hunter.result.elemental_damage += echo_ms_result.elemental_delta;
+            hunter.result.extra_damage_from_ms += echo_ms_result.applied;
+            if echo_ms_result.reflected > 0.0 {
+                hunter.hp = (hunter.hp - echo_ms_result.reflected).max(0.0);
+                hunter.result.damage_taken += echo_ms_result.reflected;
+                hunter.result.reflected_damage_taken += echo_ms_result.reflected;
+                hunter.result.damage_timeline.push((now, echo_ms_result.reflected));
+            }
         }
     }
     
     // Crippling Shots (Ozzy) - add decay stacks for NEXT attack
-    if hunter.crippling_shots > 0 && rng.gen::<f64>() < hunter.effect_chance {
-        hunter.decay_stacks += hunter.crippling_shots;
-        hunter.decay_stacks = hunter.decay_stacks.min(100);  // Cap at 100 stacks
+    if hunter.crippling_shots > 0 && rng.gen::<f64>() < hunter.effect_chance() {
+        hunter.add_stacks(StatusKind::DecayStacks, now, f64::INFINITY, 100, hunter.crippling_shots);
     }
     
     // Lifesteal
-    if hunter.lifesteal > 0.0 {
-        let healed = actual_damage * hunter.lifesteal;
-        hunter.hp = (hunter.hp + healed).min(hunter.max_hp);
+    if hunter.lifesteal() > 0.0 {
+        let healed = actual_damage * hunter.lifesteal();
+        hunter.hp = (hunter.hp + healed).min(hunter.max_hp());
         hunter.result.lifesteal += healed;
     }
     
     // Effect proc (stun)
-    if rng.gen::<f64>() < hunter.effect_chance {
+    if rng.gen::<f64>() < hunter.effect_chance() {
         hunter.result.effect_procs += 1;
         // Thousand Needles (Ozzy) adds stun duration
         let base_stun = if hunter.thousand_needles > 0 {
             hunter.thousand_needles as f64 * 0.05  // 0.05s per level
         } else {
-            1.0 + 0.2 * hunter.effect_chance
+            1.0 + 0.2 * hunter.effect_chance()
         };
         let stun_duration = base_stun;
         // 50% reduced on bosses
@@ -503,48 +716,84 @@ fn hunter_attack(hunter: &mut Hunter, enemy: &mut Enemy, rng: &mut impl Rng) {
         } else {
             stun_duration
         };
-        enemy.is_stunned = true;
-        enemy.stun_end_time = hunter.result.elapsed_time + actual_stun;
-        hunter.result.stun_duration_inflicted += actual_stun;
+        if enemy.apply_status(StatusKind::Stun, 0.0, actual_stun, now, actual_stun, rng) {
+            hunter.result.stun_duration_inflicted += actual_stun;
+        }
+    }
+
+    // Splash: a fraction of the hit also lands on nearby living targets, for builds that clear
+    // waves with multi-hit/AoE rather than single-target burst.
+    if hunter.splash_count > 0 && hunter.splash_fraction > 0.0 {
+        let splash_damage = total_damage * hunter.splash_fraction;
+        for splash_idx in pick_splash_targets(enemies, target, hunter.splash_count) {
+            let splash_result = enemies[splash_idx].take_damage_with_reflect(splash_damage, &hunter.damage_type, &hunter.race_size_bonus, rng);
+            hunter.result.damage += splash_result.applied;
+            hunter.result.elemental_damage += splash_result.elemental_delta;
+            if splash_result.reflected > 0.0 {
+                hunter.hp = (hunter.hp - splash_result.reflected).max(0.0);
+                hunter.result.damage_taken += splash_result.reflected;
+                hunter.result.reflected_damage_taken += splash_result.reflected;
+                hunter.result.damage_timeline.push((now, splash_result.reflected));
+            }
+        }
     }
+
+    // Crits also open a bleed: 10% of the crit's base damage per tick, refreshed on every
+    // subsequent crit rather than stacking indefinitely.
+    let dot = if is_crit {
+        Some((target, DotApplication {
+            kind: DotKind::Bleed,
+            damage_per_tick: base_damage * 0.1,
+            ticks: 3,
+            tick_interval: 1.0,
+            refreshable: true,
+            stacking: false,
+        }))
+    } else {
+        None
+    };
+
+    AttackOutcome { dot, deferred: Vec::new() }
 }
 
 /// Enemy attacks hunter
-fn enemy_attack(hunter: &mut Hunter, enemy: &mut Enemy, rng: &mut impl Rng) {
+fn enemy_attack(hunter: &mut Hunter, enemy: &mut Enemy, rng: &mut impl Rng, now: f64) {
     // Check for trickster evade (Ozzy) - consume a charge for free evade
-    if hunter.trickster_charges > 0 {
-        hunter.trickster_charges -= 1;
+    if hunter.spend_one_stack(StatusKind::TricksterCharges) {
         hunter.result.trickster_evades += 1;
         return;
     }
     
     // Check for evade
-    if rng.gen::<f64>() < hunter.evade_chance {
+    if rng.gen::<f64>() < hunter.evade_chance() {
         hunter.result.evades += 1;
         
         // Dance of Dashes (Ozzy) - 15% chance per level to gain trickster charge on evade
         if hunter.dance_of_dashes > 0 && rng.gen::<f64>() < hunter.dance_of_dashes as f64 * 0.15 {
-            hunter.trickster_charges += 1;
+            hunter.apply_effect(StatusKind::TricksterCharges, now, f64::INFINITY, 0.0, i32::MAX, StackingRule::AddStack);
             hunter.result.effect_procs += 1;
         }
         return;
     }
     
     // Check for block (Knox)
-    if hunter.block_chance > 0.0 && rng.gen::<f64>() < hunter.block_chance {
+    if hunter.block_chance() > 0.0 && rng.gen::<f64>() < hunter.block_chance() {
         // Blocked - reduced damage (50% of original)
         hunter.result.evades += 1;  // Track blocks via evades counter
         
         // Fortification Elixir (Knox) - +10% regen for 5 ticks after block
         if hunter.fortification_elixir > 0 {
-            hunter.empowered_block_regen += 5;
+            hunter.apply_timed_status(StatusKind::EmpoweredBlockRegen, now, 5.0);
         }
         return;
     }
     
-    // Get enemy damage
-    let (mut damage, is_crit) = enemy.get_attack_damage(rng);
-    
+    // Get enemy damage, resolving the lifesteal heal this attack generates along with it.
+    let (mut damage, is_crit, heal_amount) = enemy.resolve_attack(rng);
+    if heal_amount > 0.0 {
+        enemy.heal(heal_amount);
+    }
+
     // Weakspot Analysis (Borge) - reduce crit damage taken by 11% per level
     if is_crit && hunter.weakspot_analysis > 0 {
         let crit_reduction = hunter.weakspot_analysis as f64 * 0.11;
@@ -552,7 +801,7 @@ fn enemy_attack(hunter: &mut Hunter, enemy: &mut Enemy, rng: &mut impl Rng) {
     }
     
     // Calculate effective DR (base + deal_with_death per revive used)
-    let mut effective_dr = hunter.damage_reduction;
+    let mut effective_dr = hunter.damage_reduction();
     if hunter.deal_with_death > 0 && hunter.revive_count > 0 {
         effective_dr += hunter.deal_with_death as f64 * 0.016 * hunter.revive_count as f64;
     }
@@ -564,13 +813,20 @@ fn enemy_attack(hunter: &mut Hunter, enemy: &mut Enemy, rng: &mut impl Rng) {
     hunter.result.mitigated_damage += mitigated;
     hunter.result.damage_taken += actual_damage;
     hunter.hp -= actual_damage;
-    
+
     // Helltouch barrier (Borge)
-    if hunter.helltouch_barrier_level > 0 && hunter.hp < hunter.max_hp * 0.3 {
-        let barrier = hunter.max_hp * 0.01 * hunter.helltouch_barrier_level as f64;
+    let mut barrier_healed = 0.0;
+    if hunter.helltouch_barrier_level > 0 && hunter.hp < hunter.max_hp() * 0.3 {
+        let barrier = hunter.max_hp() * 0.01 * hunter.helltouch_barrier_level as f64;
         hunter.hp += barrier;
         hunter.result.helltouch_barrier += barrier;
+        barrier_healed = barrier;
     }
+
+    // Net incoming damage for this hit (post-mitigation, net of any healing this same tick
+    // triggered in response) - feeds the TMI burst-survivability metric in
+    // `AggregatedStats::from_results`.
+    hunter.result.damage_timeline.push((now, actual_damage - barrier_healed));
 }
 
 /// Run multiple simulations in parallel with proper thread utilization
@@ -603,13 +859,162 @@ pub fn run_simulations_sequential(config: &BuildConfig, count: usize) -> Vec<Sim
         .collect()
 }
 
-/// Run simulations and return aggregated stats
+/// Run simulations and return aggregated stats. If `config.seed` is set, runs are deterministic
+/// (see `run_and_aggregate_seeded`); otherwise each simulation draws from OS entropy.
 pub fn run_and_aggregate(config: &BuildConfig, count: usize, parallel: bool) -> AggregatedStats {
+    if let Some(seed) = config.seed {
+        return run_and_aggregate_seeded(config, count, seed, parallel);
+    }
+
     let results = if parallel {
         run_simulations_parallel(config, count)
     } else {
         run_simulations_sequential(config, count)
     };
-    
+
     AggregatedStats::from_results(&results)
 }
+
+/// Bijective integer hash used to derive independent per-iteration seeds from one master seed
+/// (Steele/Vigna's SplitMix64 finalizer). `pub(crate)` so `prng` can reuse it to expand a single
+/// seed into `CounterRng`'s 128-bit key.
+pub(crate) fn splitmix64(mut x: u64) -> u64 {
+    x = x.wrapping_add(0x9E3779B97F4A7C15);
+    let mut z = x;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+    z ^ (z >> 31)
+}
+
+/// Derive the i-th simulation's seed from a master seed, independent of run order - so the same
+/// `(seed, index)` always yields the same `SimResult` regardless of thread scheduling or whether
+/// the batch ran in parallel or sequentially.
+fn seed_for_index(seed: u64, index: usize) -> u64 {
+    seed ^ splitmix64(index as u64)
+}
+
+/// Run one simulation from a derived per-iteration seed, echoing that seed into the result so any
+/// single outlier run can be replayed exactly via `run_simulation_with_rng` + `seed_from_u64`.
+fn run_simulation_seeded_at(config: &BuildConfig, seed: u64, index: usize) -> SimResult {
+    let iter_seed = seed_for_index(seed, index);
+    let mut rng = SmallRng::seed_from_u64(iter_seed);
+    let mut result = run_simulation_with_rng(config, &mut rng);
+    result.seed = iter_seed;
+    result
+}
+
+/// Run `count` simulations sequentially, each from its own `seed ^ splitmix64(index)` RNG, so
+/// identical `seed`s yield byte-identical `SimResult`s regardless of machine or prior process
+/// state, and regardless of `parallel` vs sequential execution.
+pub fn run_simulations_sequential_seeded(config: &BuildConfig, count: usize, seed: u64) -> Vec<SimResult> {
+    (0..count)
+        .map(|i| run_simulation_seeded_at(config, seed, i))
+        .collect()
+}
+
+/// Run `count` simulations in parallel via Rayon, each from its own `seed ^ splitmix64(index)`
+/// RNG. Because each iteration's RNG only depends on its index (not on scheduling order), the
+/// i-th result is byte-identical to `run_simulations_sequential_seeded`'s i-th result.
+pub fn run_simulations_parallel_seeded(config: &BuildConfig, count: usize, seed: u64) -> Vec<SimResult> {
+    let num_cores = num_cpus::get();
+    let threads_per_hunter = (num_cores * 55 / 100).max(1);
+
+    let pool = ThreadPoolBuilder::new()
+        .num_threads(threads_per_hunter)
+        .build()
+        .unwrap_or_else(|_| rayon::ThreadPoolBuilder::new().build().unwrap());
+
+    pool.install(|| {
+        let chunk_size = (count / threads_per_hunter).max(1);
+
+        (0..count)
+            .into_par_iter()
+            .with_min_len(chunk_size.min(100))
+            .map(|i| run_simulation_seeded_at(config, seed, i))
+            .collect()
+    })
+}
+
+/// Run simulations and return aggregated stats, seeded for reproducibility. `parallel` picks
+/// between `run_simulations_parallel_seeded`/`run_simulations_sequential_seeded`; both yield the
+/// same per-index results for a given `seed`, so either is safe to diff against the other.
+pub fn run_and_aggregate_seeded(config: &BuildConfig, count: usize, seed: u64, parallel: bool) -> AggregatedStats {
+    let results = if parallel {
+        run_simulations_parallel_seeded(config, count, seed)
+    } else {
+        run_simulations_sequential_seeded(config, count, seed)
+    };
+    AggregatedStats::from_results(&results)
+}
+
+/// Run `count` simulations sequentially, seeded like `run_simulations_sequential_seeded`, while
+/// also building a `StageProfile` of per-stage loot across the whole batch.
+pub fn run_simulations_sequential_profiled(config: &BuildConfig, count: usize, seed: u64) -> (Vec<SimResult>, StageProfile) {
+    let mut profile = StageProfile::new();
+    let results = (0..count)
+        .map(|i| {
+            let iter_seed = seed_for_index(seed, i);
+            let mut rng = SmallRng::seed_from_u64(iter_seed);
+            let mut result = run_simulation_with_rng_profiled(config, &mut rng, Some(&mut profile));
+            result.seed = iter_seed;
+            result
+        })
+        .collect();
+    (results, profile)
+}
+
+/// Run `count` simulations in parallel via Rayon, seeded like `run_simulations_parallel_seeded`,
+/// while also building a `StageProfile` of per-stage loot across the whole batch. Each run
+/// records into its own small `StageProfile` - no cross-thread locking - and the per-run
+/// profiles are merged together at the end via `StageProfile::merge`.
+pub fn run_simulations_parallel_profiled(config: &BuildConfig, count: usize, seed: u64) -> (Vec<SimResult>, StageProfile) {
+    let num_cores = num_cpus::get();
+    let threads_per_hunter = (num_cores * 55 / 100).max(1);
+
+    let pool = ThreadPoolBuilder::new()
+        .num_threads(threads_per_hunter)
+        .build()
+        .unwrap_or_else(|_| rayon::ThreadPoolBuilder::new().build().unwrap());
+
+    // Each run gets its own small per-run `StageProfile` (at most one sample per stage reached)
+    // rather than sharing one behind a lock; `.collect()` on an `IndexedParallelIterator`
+    // preserves index order, so `results[i]` still matches `run_simulations_sequential_profiled`'s
+    // i-th result, and the per-run profiles are merged together afterward.
+    let per_run: Vec<(SimResult, StageProfile)> = pool.install(|| {
+        let chunk_size = (count / threads_per_hunter).max(1);
+
+        (0..count)
+            .into_par_iter()
+            .with_min_len(chunk_size.min(100))
+            .map(|i| {
+                let iter_seed = seed_for_index(seed, i);
+                let mut rng = SmallRng::seed_from_u64(iter_seed);
+                let mut profile = StageProfile::new();
+                let mut result = run_simulation_with_rng_profiled(config, &mut rng, Some(&mut profile));
+                result.seed = iter_seed;
+                (result, profile)
+            })
+            .collect()
+    });
+
+    let mut profile = StageProfile::new();
+    let results = per_run
+        .into_iter()
+        .map(|(result, run_profile)| {
+            profile.merge(&run_profile);
+            result
+        })
+        .collect();
+    (results, profile)
+}
+
+/// Run simulations and return both aggregated stats and a per-stage loot `StageProfile`, seeded
+/// for reproducibility like `run_and_aggregate_seeded`.
+pub fn run_and_aggregate_profiled(config: &BuildConfig, count: usize, seed: u64, parallel: bool) -> (AggregatedStats, StageProfile) {
+    let (results, profile) = if parallel {
+        run_simulations_parallel_profiled(config, count, seed)
+    } else {
+        run_simulations_sequential_profiled(config, count, seed)
+    };
+    (AggregatedStats::from_results(&results), profile)
+}
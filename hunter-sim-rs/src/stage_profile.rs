@@ -0,0 +1,146 @@
+//! Per-stage loot profiling via Welford's online algorithm.
+//!
+//! Sweeping thousands of runs through `run_and_aggregate` only ever surfaces a single aggregate
+//! `avg_loot` - where loot concentrates stage-by-stage is invisible. `StageProfile` fills that gap
+//! by recording, for every `current_stage` a run passes through, a running count/mean/variance/
+//! min/max of that stage's loot - without ever storing a raw sample, so profiling a multi-hour
+//! sweep costs O(stages reached) memory rather than O(runs * stages).
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Running count/mean/variance/min/max for one stage's loot, updated one sample at a time via
+/// Welford's online algorithm (`count`, `mean`, `M2`), so `variance` never needs the underlying
+/// samples kept around.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct WelfordAccumulator {
+    pub count: u64,
+    pub mean: f64,
+    m2: f64,
+    pub min: f64,
+    pub max: f64,
+}
+
+impl Default for WelfordAccumulator {
+    fn default() -> Self {
+        Self { count: 0, mean: 0.0, m2: 0.0, min: f64::INFINITY, max: f64::NEG_INFINITY }
+    }
+}
+
+impl WelfordAccumulator {
+    /// Fold one more sample in: `count += 1; delta = x - mean; mean += delta/count; M2 +=
+    /// delta*(x-mean)`.
+    pub fn record(&mut self, x: f64) {
+        self.count += 1;
+        let delta = x - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (x - self.mean);
+        self.min = self.min.min(x);
+        self.max = self.max.max(x);
+    }
+
+    /// Sample variance (`M2 / (count - 1)`); `0.0` until there are at least two samples.
+    pub fn variance(&self) -> f64 {
+        if self.count < 2 {
+            0.0
+        } else {
+            self.m2 / (self.count - 1) as f64
+        }
+    }
+
+    pub fn std_dev(&self) -> f64 {
+        self.variance().sqrt()
+    }
+
+    /// Fold `other`'s samples into `self` without replaying them, via Chan et al.'s parallel
+    /// merge formula - what lets each worker thread keep its own accumulator and combine at the
+    /// end instead of sharing one behind a lock.
+    pub fn merge(&mut self, other: &WelfordAccumulator) {
+        if other.count == 0 {
+            return;
+        }
+        if self.count == 0 {
+            *self = *other;
+            return;
+        }
+        let count = self.count + other.count;
+        let delta = other.mean - self.mean;
+        let mean = self.mean + delta * other.count as f64 / count as f64;
+        let m2 = self.m2 + other.m2 + delta * delta * (self.count as f64 * other.count as f64) / count as f64;
+        self.count = count;
+        self.mean = mean;
+        self.m2 = m2;
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+    }
+}
+
+/// One `WelfordAccumulator` per stage reached across a batch of runs.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StageProfile {
+    stages: BTreeMap<i32, WelfordAccumulator>,
+}
+
+/// One stage's row in `StageProfile::to_csv`/`to_json`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StageProfileRow {
+    pub stage: i32,
+    pub count: u64,
+    pub mean_loot: f64,
+    pub variance: f64,
+    pub std_dev: f64,
+    pub min_loot: f64,
+    pub max_loot: f64,
+}
+
+impl StageProfile {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fold one stage-completion sample into its stage's accumulator.
+    pub fn record(&mut self, stage: i32, loot: f64) {
+        self.stages.entry(stage).or_default().record(loot);
+    }
+
+    /// Merge another profile's per-stage accumulators into this one, stage by stage.
+    pub fn merge(&mut self, other: &StageProfile) {
+        for (stage, acc) in &other.stages {
+            self.stages.entry(*stage).or_default().merge(acc);
+        }
+    }
+
+    /// Rows sorted by ascending stage, ready to serialize as CSV or JSON.
+    pub fn rows(&self) -> Vec<StageProfileRow> {
+        self.stages
+            .iter()
+            .map(|(&stage, acc)| StageProfileRow {
+                stage,
+                count: acc.count,
+                mean_loot: acc.mean,
+                variance: acc.variance(),
+                std_dev: acc.std_dev(),
+                min_loot: acc.min,
+                max_loot: acc.max,
+            })
+            .collect()
+    }
+
+    /// Render as a CSV histogram: header row plus one row per stage reached.
+    pub fn to_csv(&self) -> String {
+        let mut out = String::from("stage,count,mean_loot,variance,std_dev,min_loot,max_loot\n");
+        for row in self.rows() {
+            out.push_str(&format!(
+                "{},{},{},{},{},{},{}\n",
+                row.stage, row.count, row.mean_loot, row.variance, row.std_dev, row.min_loot, row.max_loot
+            ));
+        }
+        out
+    }
+
+    /// Render as a JSON array of per-stage rows.
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!(self.rows())
+    }
+}
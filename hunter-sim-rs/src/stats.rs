@@ -1,16 +1,107 @@
 //! Simulation result statistics
 
+use std::cmp::Ordering;
+
 use serde::{Deserialize, Serialize};
 
+/// Full distribution summary for one collected metric (stage, elapsed time, loot, ...).
+/// `avg_*`/`std_stage`-style single numbers hide skew - e.g. a build that usually dies early but
+/// occasionally runs away with the stage count would report a middling `avg_stage` that neither
+/// outcome actually looks like. Percentiles are computed once per metric by sorting the full
+/// sample vector and interpolating between the two closest ranks (NumPy's default `linear`
+/// method), rather than maintained incrementally, since `from_results` already has every sample
+/// in hand.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct DistributionMetrics {
+    pub mean: f64,
+    pub stddev: f64,
+    pub min: f64,
+    pub max: f64,
+    pub p25: f64,
+    pub median: f64,
+    pub p75: f64,
+    pub p90: f64,
+    pub p95: f64,
+    pub p99: f64,
+}
+
+impl DistributionMetrics {
+    /// Compute every field from `values` in one pass-and-sort. Empty input yields all-zero
+    /// `DistributionMetrics`, matching `AggregatedStats::default()` for an empty result set.
+    pub fn from_values(values: &[f64]) -> Self {
+        if values.is_empty() {
+            return Self::default();
+        }
+
+        let mut sorted = values.to_vec();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+
+        let n = sorted.len();
+        let mean = sorted.iter().sum::<f64>() / n as f64;
+        let variance = sorted.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / n as f64;
+
+        let percentile = |p: f64| -> f64 {
+            if n == 1 {
+                return sorted[0];
+            }
+            let rank = p * (n - 1) as f64;
+            let lower = rank.floor() as usize;
+            let upper = rank.ceil() as usize;
+            if lower == upper {
+                return sorted[lower];
+            }
+            let frac = rank - lower as f64;
+            sorted[lower] + (sorted[upper] - sorted[lower]) * frac
+        };
+
+        Self {
+            mean,
+            stddev: variance.sqrt(),
+            min: sorted[0],
+            max: sorted[n - 1],
+            p25: percentile(0.25),
+            median: percentile(0.5),
+            p75: percentile(0.75),
+            p90: percentile(0.90),
+            p95: percentile(0.95),
+            p99: percentile(0.99),
+        }
+    }
+}
+
 /// Results from a single simulation run
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct SimResult {
     pub final_stage: i32,
+    /// The RNG seed this run was derived from, when run via a seeded entry point (`seed ^
+    /// splitmix64(index)`); 0 for entropy-seeded runs, so any single outlier can be replayed
+    /// exactly.
+    pub seed: u64,
     pub elapsed_time: f64,
     pub kills: i32,
     pub damage: f64,
+    /// Portion of `damage` that came from ticking DoTs rather than direct hits.
+    pub dot_damage: f64,
+    /// Net bonus (positive) or penalty (negative) damage attributable to the attack/defense
+    /// element matchup, via `Enemy::take_damage_with_reflect`.
+    pub elemental_damage: f64,
     pub damage_taken: f64,
+    /// The hunter's max HP at the end of the run, used to normalize `damage_timeline` into the
+    /// TMI burst-survivability metric.
+    pub max_hp: f64,
+    /// `(timestamp, net_damage)` for every landed enemy hit and every boss thorns reflect -
+    /// `net_damage` is post-mitigation and net of any same-tick reactive healing (e.g. Helltouch
+    /// barrier). Feeds the sliding-window
+    /// TMI calculation in `AggregatedStats::from_results`.
+    pub damage_timeline: Vec<(f64, f64)>,
     pub total_loot: f64,
+    /// Per-tier split of `total_loot`, from `Hunter::calculate_loot`.
+    pub loot_common: f64,
+    pub loot_uncommon: f64,
+    pub loot_rare: f64,
+    /// Accumulated XP, scaled independently by `RateConfig::xp_rate`/`level_penalty` so
+    /// over-leveled and on-level builds can be compared on farming efficiency.
+    pub total_xp: f64,
     pub attacks: i32,
     pub crits: i32,
     pub extra_damage_from_crits: f64,
@@ -20,6 +111,9 @@ pub struct SimResult {
     pub regenerated_hp: f64,
     pub lifesteal: f64,
     pub mitigated_damage: f64,
+    /// Portion of `damage_taken` that came from a boss's thorn reflect rather than its own
+    /// attacks, via `Enemy::take_damage_with_reflect`.
+    pub reflected_damage_taken: f64,
     pub effect_procs: i32,
     pub stun_duration_inflicted: f64,
     // Hunter-specific stats
@@ -44,8 +138,21 @@ pub struct AggregatedStats {
     pub avg_time: f64,
     pub avg_loot: f64,
     pub avg_loot_per_hour: f64,
+    pub avg_loot_common: f64,
+    pub avg_loot_uncommon: f64,
+    pub avg_loot_rare: f64,
+    pub avg_xp: f64,
+    pub avg_xp_per_hour: f64,
     pub avg_damage: f64,
+    pub avg_dot_damage: f64,
+    pub avg_elemental_damage: f64,
     pub avg_damage_taken: f64,
+    /// Mean "Theoretical Maximum Incoming-damage" burst-survivability score across runs (see
+    /// `compute_tmi`) - a log-sum-exp over sliding `DEFAULT_TMI_WINDOW`-second windows of net
+    /// incoming damage, scaled so `10.0` means a window hit for 10% of max HP. Lower is safer.
+    pub avg_tmi: f64,
+    /// The single worst run's TMI score - the closest any run came to a lethal burst.
+    pub max_tmi: f64,
     pub avg_mitigated: f64,
     pub avg_lifesteal: f64,
     pub avg_attacks: f64,
@@ -61,15 +168,131 @@ pub struct AggregatedStats {
     pub boss3_survival: f64,  // % that reached stage > 300
     pub boss4_survival: f64,  // % that reached stage > 400
     pub boss5_survival: f64,  // % that reached stage > 500
+    /// Full percentile breakdowns for the four metrics where the mean is most often misleading.
+    pub stage_distribution: DistributionMetrics,
+    pub time_distribution: DistributionMetrics,
+    pub loot_distribution: DistributionMetrics,
+    pub loot_per_hour_distribution: DistributionMetrics,
+    /// 95% confidence-interval half-width on `avg_stage` (`z_score(0.95) * std_stage / sqrt(runs)`).
+    /// Use `stage_ci_halfwidth_at`/`converged` for other confidence levels.
+    pub stage_ci_halfwidth: f64,
+    /// Same as `stage_ci_halfwidth`, but for `avg_loot_per_hour` - the secondary metric callers
+    /// most often also want to converge on before stopping a sweep early.
+    pub loot_per_hour_ci_halfwidth: f64,
+}
+
+/// Default sliding burst window (seconds) for the TMI metric; override via
+/// `AggregatedStats::from_results_with_tmi_window`.
+pub const DEFAULT_TMI_WINDOW: f64 = 6.0;
+
+/// "Theoretical Maximum Incoming-damage" for one run: slide a `window`-second burst window across
+/// `timeline`, express each window's summed net damage as a fraction `f_i` of `max_hp`, then
+/// combine across window positions via a log-sum-exp (`ln(mean(exp(10*f_i))) / 10 * 100`) so a
+/// single brutal spike dominates the score the way it would dominate a player's risk of dying,
+/// rather than averaging it away. Windows at the start of the run that are shorter than `window`
+/// are scored against whatever's been recorded so far rather than skipped. Runs with no recorded
+/// damage (or no max HP to normalize against) score `0.0`. `pub(crate)` so `StatsAccumulator` can
+/// reuse it when folding a `SimResult` into a running TMI average.
+pub(crate) fn compute_tmi(timeline: &[(f64, f64)], max_hp: f64, window: f64) -> f64 {
+    if timeline.is_empty() || max_hp <= 0.0 {
+        return 0.0;
+    }
+
+    let mut exp_sum = 0.0;
+    for i in 0..timeline.len() {
+        let end = timeline[i].0;
+        let start = end - window;
+        let mut window_sum = 0.0;
+        for &(t, net_damage) in timeline[..=i].iter().rev() {
+            if t < start {
+                break;
+            }
+            window_sum += net_damage;
+        }
+        let f_i = window_sum / max_hp;
+        exp_sum += (10.0 * f_i).exp();
+    }
+
+    (exp_sum / timeline.len() as f64).ln() / 10.0 * 100.0
+}
+
+/// Inverse standard-normal CDF via Peter Acklam's rational approximation (accurate to about
+/// 1.15e-9), used to turn a confidence level into a z-score without pulling in a stats crate.
+fn inverse_normal_cdf(p: f64) -> f64 {
+    const A: [f64; 6] = [
+        -3.969683028665376e+01,
+        2.209460984245205e+02,
+        -2.759285104469687e+02,
+        1.383577518672690e+02,
+        -3.066479806614716e+01,
+        2.506628277459239e+00,
+    ];
+    const B: [f64; 5] = [
+        -5.447609879822406e+01,
+        1.615858368580409e+02,
+        -1.556989798598866e+02,
+        6.680131188771972e+01,
+        -1.328068155288572e+01,
+    ];
+    const C: [f64; 6] = [
+        -7.784894002430293e-03,
+        -3.223964580411365e-01,
+        -2.400758277161838e+00,
+        -2.549732539343734e+00,
+        4.374664141464968e+00,
+        2.938163982698783e+00,
+    ];
+    const D: [f64; 4] = [
+        7.784695709041462e-03,
+        3.224671290700398e-01,
+        2.445134137142996e+00,
+        3.754408661907416e+00,
+    ];
+
+    let p_low = 0.02425;
+    let p_high = 1.0 - p_low;
+
+    if p < p_low {
+        let q = (-2.0 * p.ln()).sqrt();
+        (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    } else if p <= p_high {
+        let q = p - 0.5;
+        let r = q * q;
+        (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+            / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.0)
+    } else {
+        let q = (-2.0 * (1.0 - p).ln()).sqrt();
+        -(((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    }
+}
+
+/// z-score for a two-sided confidence interval at the given `confidence` (e.g. `0.95` -> `1.96`).
+/// `pub(crate)` so `StatsAccumulator` can compute the same CI half-widths incrementally.
+pub(crate) fn z_score(confidence: f64) -> f64 {
+    inverse_normal_cdf(0.5 + confidence / 2.0)
+}
+
+/// Standard error -> confidence-interval half-width: `z(confidence) * stderr`.
+fn ci_halfwidth_from(stderr: f64, confidence: f64) -> f64 {
+    z_score(confidence) * stderr
 }
 
 impl AggregatedStats {
-    /// Create aggregated stats from a list of simulation results
+    /// Create aggregated stats from a list of simulation results, using `DEFAULT_TMI_WINDOW` for
+    /// the TMI burst-survivability metric.
     pub fn from_results(results: &[SimResult]) -> Self {
+        Self::from_results_with_tmi_window(results, DEFAULT_TMI_WINDOW)
+    }
+
+    /// Same as `from_results`, but with an explicit TMI sliding-window size (seconds) instead of
+    /// `DEFAULT_TMI_WINDOW`.
+    pub fn from_results_with_tmi_window(results: &[SimResult], tmi_window: f64) -> Self {
         if results.is_empty() {
             return Self::default();
         }
-        
+
         let n = results.len() as f64;
         let stages: Vec<i32> = results.iter().map(|r| r.final_stage).collect();
         let times: Vec<f64> = results.iter().map(|r| r.elapsed_time).collect();
@@ -94,7 +317,29 @@ impl AggregatedStats {
                 }
             })
             .collect();
-        
+
+        let xp_per_hours: Vec<f64> = results
+            .iter()
+            .map(|r| {
+                if r.elapsed_time > 0.0 {
+                    r.total_xp / (r.elapsed_time / 3600.0)
+                } else {
+                    0.0
+                }
+            })
+            .collect();
+
+        let tmis: Vec<f64> = results
+            .iter()
+            .map(|r| compute_tmi(&r.damage_timeline, r.max_hp, tmi_window))
+            .collect();
+
+        let stage_values: Vec<f64> = stages.iter().map(|&s| s as f64).collect();
+        let stage_distribution = DistributionMetrics::from_values(&stage_values);
+        let time_distribution = DistributionMetrics::from_values(&times);
+        let loot_distribution = DistributionMetrics::from_values(&loots);
+        let loot_per_hour_distribution = DistributionMetrics::from_values(&loot_per_hours);
+
         // Count boss deaths (died at stage ending in 00) - legacy metric
         let boss_deaths = stages.iter().filter(|&&s| s % 100 == 0 && s > 0).count();
         
@@ -114,8 +359,17 @@ impl AggregatedStats {
             avg_time: times.iter().sum::<f64>() / n,
             avg_loot: loots.iter().sum::<f64>() / n,
             avg_loot_per_hour: loot_per_hours.iter().sum::<f64>() / n,
+            avg_loot_common: results.iter().map(|r| r.loot_common).sum::<f64>() / n,
+            avg_loot_uncommon: results.iter().map(|r| r.loot_uncommon).sum::<f64>() / n,
+            avg_loot_rare: results.iter().map(|r| r.loot_rare).sum::<f64>() / n,
+            avg_xp: results.iter().map(|r| r.total_xp).sum::<f64>() / n,
+            avg_xp_per_hour: xp_per_hours.iter().sum::<f64>() / n,
             avg_damage: results.iter().map(|r| r.damage).sum::<f64>() / n,
+            avg_dot_damage: results.iter().map(|r| r.dot_damage).sum::<f64>() / n,
+            avg_elemental_damage: results.iter().map(|r| r.elemental_damage).sum::<f64>() / n,
             avg_damage_taken: results.iter().map(|r| r.damage_taken).sum::<f64>() / n,
+            avg_tmi: tmis.iter().sum::<f64>() / n,
+            max_tmi: tmis.iter().cloned().fold(f64::NEG_INFINITY, f64::max),
             avg_mitigated: results.iter().map(|r| r.mitigated_damage).sum::<f64>() / n,
             avg_lifesteal: results.iter().map(|r| r.lifesteal).sum::<f64>() / n,
             avg_attacks: results.iter().map(|r| r.attacks as f64).sum::<f64>() / n,
@@ -130,6 +384,43 @@ impl AggregatedStats {
             boss3_survival: boss3_passed as f64 / n,
             boss4_survival: boss4_passed as f64 / n,
             boss5_survival: boss5_passed as f64 / n,
+            stage_distribution,
+            time_distribution,
+            loot_distribution,
+            loot_per_hour_distribution,
+            stage_ci_halfwidth: ci_halfwidth_from(std_stage / n.sqrt(), 0.95),
+            loot_per_hour_ci_halfwidth: ci_halfwidth_from(loot_per_hour_distribution.stddev / n.sqrt(), 0.95),
+        }
+    }
+
+    /// Standard error of `avg_stage`: `std_stage / sqrt(runs)`.
+    pub fn stage_std_error(&self) -> f64 {
+        if self.runs <= 0 {
+            return 0.0;
+        }
+        self.std_stage / (self.runs as f64).sqrt()
+    }
+
+    /// Confidence-interval half-width on `avg_stage` at an arbitrary `confidence` (e.g. `0.99` for
+    /// a 99% CI), rather than the 95% default baked into `stage_ci_halfwidth`.
+    pub fn stage_ci_halfwidth_at(&self, confidence: f64) -> f64 {
+        ci_halfwidth_from(self.stage_std_error(), confidence)
+    }
+
+    /// True once `avg_stage`'s confidence interval at `confidence` has shrunk to
+    /// `target_ci_halfwidth` or below - i.e. more runs would no longer meaningfully sharpen the
+    /// estimate. A simulation driver can poll this after each batch and stop early.
+    pub fn converged(&self, target_ci_halfwidth: f64, confidence: f64) -> bool {
+        self.runs > 0 && self.stage_ci_halfwidth_at(confidence) <= target_ci_halfwidth
+    }
+
+    /// Same as `converged`, but for `avg_loot_per_hour` instead of `avg_stage` - the secondary
+    /// metric callers most often also want to converge on before stopping a sweep early.
+    pub fn loot_per_hour_converged(&self, target_ci_halfwidth: f64, confidence: f64) -> bool {
+        if self.runs <= 0 {
+            return false;
         }
+        let stderr = self.loot_per_hour_distribution.stddev / (self.runs as f64).sqrt();
+        ci_halfwidth_from(stderr, confidence) <= target_ci_halfwidth
     }
 }
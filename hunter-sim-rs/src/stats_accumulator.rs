@@ -0,0 +1,243 @@
+//! Streaming, mergeable alternative to `AggregatedStats::from_results`.
+//!
+//! `from_results` needs the entire `&[SimResult]` slice resident at once and computes variance
+//! with the naive single-pass `sum((x-mean)^2)/n`, which loses precision once run counts get
+//! large. `StatsAccumulator` instead folds one `SimResult` at a time into a `WelfordAccumulator`
+//! per numeric field (mean/variance tracked incrementally, no samples retained), and `merge`
+//! combines two accumulators via Chan et al.'s parallel Welford formula - so a batch can be split
+//! across worker threads, each folding its own partition, and the partials combined into one
+//! final `AggregatedStats` without ever materializing the full result set in one place.
+//!
+//! The trade-off: `DistributionMetrics`'s percentiles are fundamentally a function of the sorted
+//! sample set, not a running mean/variance, so `finalize()` leaves the `*_distribution` fields at
+//! their default (all-zero) value. Reach for `AggregatedStats::from_results` instead when
+//! percentiles matter and the full slice is available.
+
+use crate::stage_profile::WelfordAccumulator;
+use crate::stats::{compute_tmi, z_score, AggregatedStats, DistributionMetrics, SimResult, DEFAULT_TMI_WINDOW};
+
+/// Incremental accumulator: one `WelfordAccumulator` per numeric `AggregatedStats` field, plus
+/// plain running counts for the boss-survival proportions.
+#[derive(Debug, Clone)]
+pub struct StatsAccumulator {
+    tmi_window: f64,
+    count: u64,
+    stage: WelfordAccumulator,
+    time: WelfordAccumulator,
+    loot: WelfordAccumulator,
+    loot_per_hour: WelfordAccumulator,
+    loot_common: WelfordAccumulator,
+    loot_uncommon: WelfordAccumulator,
+    loot_rare: WelfordAccumulator,
+    xp: WelfordAccumulator,
+    xp_per_hour: WelfordAccumulator,
+    damage: WelfordAccumulator,
+    dot_damage: WelfordAccumulator,
+    elemental_damage: WelfordAccumulator,
+    damage_taken: WelfordAccumulator,
+    tmi: WelfordAccumulator,
+    mitigated: WelfordAccumulator,
+    lifesteal: WelfordAccumulator,
+    attacks: WelfordAccumulator,
+    crits: WelfordAccumulator,
+    kills: WelfordAccumulator,
+    evades: WelfordAccumulator,
+    effect_procs: WelfordAccumulator,
+    stun_duration: WelfordAccumulator,
+    boss_deaths: u64,
+    boss1_passed: u64,
+    boss2_passed: u64,
+    boss3_passed: u64,
+    boss4_passed: u64,
+    boss5_passed: u64,
+}
+
+impl Default for StatsAccumulator {
+    fn default() -> Self {
+        Self::new(DEFAULT_TMI_WINDOW)
+    }
+}
+
+impl StatsAccumulator {
+    /// New, empty accumulator. `tmi_window` is the sliding-window size (seconds) used to score
+    /// each pushed run's TMI, matching `AggregatedStats::from_results_with_tmi_window`.
+    pub fn new(tmi_window: f64) -> Self {
+        Self {
+            tmi_window,
+            count: 0,
+            stage: WelfordAccumulator::default(),
+            time: WelfordAccumulator::default(),
+            loot: WelfordAccumulator::default(),
+            loot_per_hour: WelfordAccumulator::default(),
+            loot_common: WelfordAccumulator::default(),
+            loot_uncommon: WelfordAccumulator::default(),
+            loot_rare: WelfordAccumulator::default(),
+            xp: WelfordAccumulator::default(),
+            xp_per_hour: WelfordAccumulator::default(),
+            damage: WelfordAccumulator::default(),
+            dot_damage: WelfordAccumulator::default(),
+            elemental_damage: WelfordAccumulator::default(),
+            damage_taken: WelfordAccumulator::default(),
+            tmi: WelfordAccumulator::default(),
+            mitigated: WelfordAccumulator::default(),
+            lifesteal: WelfordAccumulator::default(),
+            attacks: WelfordAccumulator::default(),
+            crits: WelfordAccumulator::default(),
+            kills: WelfordAccumulator::default(),
+            evades: WelfordAccumulator::default(),
+            effect_procs: WelfordAccumulator::default(),
+            stun_duration: WelfordAccumulator::default(),
+            boss_deaths: 0,
+            boss1_passed: 0,
+            boss2_passed: 0,
+            boss3_passed: 0,
+            boss4_passed: 0,
+            boss5_passed: 0,
+        }
+    }
+
+    /// Fold one more `SimResult` in.
+    pub fn push(&mut self, result: &SimResult) {
+        self.count += 1;
+
+        let stage = result.final_stage as f64;
+        self.stage.record(stage);
+        self.time.record(result.elapsed_time);
+        self.loot.record(result.total_loot);
+        let loot_per_hour = if result.elapsed_time > 0.0 {
+            result.total_loot / (result.elapsed_time / 3600.0)
+        } else {
+            0.0
+        };
+        self.loot_per_hour.record(loot_per_hour);
+        self.loot_common.record(result.loot_common);
+        self.loot_uncommon.record(result.loot_uncommon);
+        self.loot_rare.record(result.loot_rare);
+        self.xp.record(result.total_xp);
+        let xp_per_hour = if result.elapsed_time > 0.0 {
+            result.total_xp / (result.elapsed_time / 3600.0)
+        } else {
+            0.0
+        };
+        self.xp_per_hour.record(xp_per_hour);
+        self.damage.record(result.damage);
+        self.dot_damage.record(result.dot_damage);
+        self.elemental_damage.record(result.elemental_damage);
+        self.damage_taken.record(result.damage_taken);
+        self.tmi.record(compute_tmi(&result.damage_timeline, result.max_hp, self.tmi_window));
+        self.mitigated.record(result.mitigated_damage);
+        self.lifesteal.record(result.lifesteal);
+        self.attacks.record(result.attacks as f64);
+        self.crits.record(result.crits as f64);
+        self.kills.record(result.kills as f64);
+        self.evades.record(result.evades as f64);
+        self.effect_procs.record(result.effect_procs as f64);
+        self.stun_duration.record(result.stun_duration_inflicted);
+
+        if result.final_stage % 100 == 0 && result.final_stage > 0 {
+            self.boss_deaths += 1;
+        }
+        if result.final_stage > 100 {
+            self.boss1_passed += 1;
+        }
+        if result.final_stage > 200 {
+            self.boss2_passed += 1;
+        }
+        if result.final_stage > 300 {
+            self.boss3_passed += 1;
+        }
+        if result.final_stage > 400 {
+            self.boss4_passed += 1;
+        }
+        if result.final_stage > 500 {
+            self.boss5_passed += 1;
+        }
+    }
+
+    /// Combine `other`'s partition into `self` via the parallel Welford formula, field by field.
+    pub fn merge(&mut self, other: &StatsAccumulator) {
+        self.count += other.count;
+        self.stage.merge(&other.stage);
+        self.time.merge(&other.time);
+        self.loot.merge(&other.loot);
+        self.loot_per_hour.merge(&other.loot_per_hour);
+        self.loot_common.merge(&other.loot_common);
+        self.loot_uncommon.merge(&other.loot_uncommon);
+        self.loot_rare.merge(&other.loot_rare);
+        self.xp.merge(&other.xp);
+        self.xp_per_hour.merge(&other.xp_per_hour);
+        self.damage.merge(&other.damage);
+        self.dot_damage.merge(&other.dot_damage);
+        self.elemental_damage.merge(&other.elemental_damage);
+        self.damage_taken.merge(&other.damage_taken);
+        self.tmi.merge(&other.tmi);
+        self.mitigated.merge(&other.mitigated);
+        self.lifesteal.merge(&other.lifesteal);
+        self.attacks.merge(&other.attacks);
+        self.crits.merge(&other.crits);
+        self.kills.merge(&other.kills);
+        self.evades.merge(&other.evades);
+        self.effect_procs.merge(&other.effect_procs);
+        self.stun_duration.merge(&other.stun_duration);
+        self.boss_deaths += other.boss_deaths;
+        self.boss1_passed += other.boss1_passed;
+        self.boss2_passed += other.boss2_passed;
+        self.boss3_passed += other.boss3_passed;
+        self.boss4_passed += other.boss4_passed;
+        self.boss5_passed += other.boss5_passed;
+    }
+
+    /// Produce an `AggregatedStats` from the accumulated totals. `*_distribution` fields are left
+    /// at their default (all-zero) `DistributionMetrics`, since percentiles can't be derived from
+    /// a running mean/variance - see the module doc comment.
+    pub fn finalize(&self) -> AggregatedStats {
+        if self.count == 0 {
+            return AggregatedStats::default();
+        }
+
+        let n = self.count as f64;
+        let stage_ci_halfwidth = z_score(0.95) * self.stage.std_dev() / n.sqrt();
+        let loot_per_hour_ci_halfwidth = z_score(0.95) * self.loot_per_hour.std_dev() / n.sqrt();
+        AggregatedStats {
+            runs: self.count as i32,
+            avg_stage: self.stage.mean,
+            std_stage: self.stage.std_dev(),
+            min_stage: self.stage.min.round() as i32,
+            max_stage: self.stage.max.round() as i32,
+            avg_time: self.time.mean,
+            avg_loot: self.loot.mean,
+            avg_loot_per_hour: self.loot_per_hour.mean,
+            avg_loot_common: self.loot_common.mean,
+            avg_loot_uncommon: self.loot_uncommon.mean,
+            avg_loot_rare: self.loot_rare.mean,
+            avg_xp: self.xp.mean,
+            avg_xp_per_hour: self.xp_per_hour.mean,
+            avg_damage: self.damage.mean,
+            avg_dot_damage: self.dot_damage.mean,
+            avg_elemental_damage: self.elemental_damage.mean,
+            avg_damage_taken: self.damage_taken.mean,
+            avg_tmi: self.tmi.mean,
+            max_tmi: self.tmi.max,
+            avg_mitigated: self.mitigated.mean,
+            avg_lifesteal: self.lifesteal.mean,
+            avg_attacks: self.attacks.mean,
+            avg_crits: self.crits.mean,
+            avg_kills: self.kills.mean,
+            avg_evades: self.evades.mean,
+            avg_effect_procs: self.effect_procs.mean,
+            avg_stun_duration: self.stun_duration.mean,
+            survival_rate: 1.0 - (self.boss_deaths as f64 / n),
+            boss1_survival: self.boss1_passed as f64 / n,
+            boss2_survival: self.boss2_passed as f64 / n,
+            boss3_survival: self.boss3_passed as f64 / n,
+            boss4_survival: self.boss4_passed as f64 / n,
+            boss5_survival: self.boss5_passed as f64 / n,
+            stage_distribution: DistributionMetrics::default(),
+            time_distribution: DistributionMetrics::default(),
+            loot_distribution: DistributionMetrics::default(),
+            loot_per_hour_distribution: DistributionMetrics::default(),
+            stage_ci_halfwidth,
+            loot_per_hour_ci_halfwidth,
+        }
+    }
+}